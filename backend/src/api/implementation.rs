@@ -1,7 +1,86 @@
-use actix_web::{web::Data, HttpResponse, Responder};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+    sync::Mutex,
+};
+
+use actix_web::{
+    web::{Data, Path, Query},
+    HttpResponse, Responder,
+};
+use data_core::{
+    Budget, DataCoreBackend, EmpireData, EmpireIdentity, LeaderData, PlanetData, StellarisDate,
+};
 use data_model::ModelCustodian;
+use futures::StreamExt;
+use serde::Deserialize;
+use stellarust::dto::{
+    BudgetComponent, BudgetDto, ColoniesDto, ColonySnapshotDto, DemographicsSeriesDto,
+    DiplomacyDto, DiplomacySnapshotDto, FederationDto, FleetSeriesDto, GalacticCommunityDto,
+    GalaxyDto, HyperlaneDto, IdentityChangeDto, IdentityChangeKind, IdentityDto,
+    IdentitySnapshotDto, LeaderEventDto, LeaderEventKind, LeaderEventsDto, LeaderboardDto,
+    LeaderboardEntryDto, LeaderboardSnapshotDto, MarketDto, MegastructureDto,
+    MegastructureStageDto, MegastructuresDto, MilitarySeriesDto, PlanetDto, PolicyDto, RelationDto,
+    RelationMatrixDto, RelationSnapshotDto, ResourceBudgetDto, ResourceClass, ResourceSeriesDto,
+    SystemDto, TechnologiesDto, TechnologyDto, TradeDto, TradeHubDto, TradeRouteDto,
+    TradeSnapshotDto, TradeVolumeSeriesDto, TruceDto, WarDto, WarsDto,
+};
+use strum::IntoEnumIterator;
+
+use crate::broadcaster::Broadcaster;
+
+type BudgetMap = HashMap<ResourceClass, Vec<(String, f64)>>;
+type EmpireHistory = Vec<(StellarisDate, EmpireData)>;
+type DateRange = (Option<StellarisDate>, Option<StellarisDate>);
+
+#[derive(Deserialize)]
+pub struct ResourcesQuery {
+    from: Option<String>,
+    to: Option<String>,
+    resource: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+impl ResourcesQuery {
+    fn date_range(&self) -> Result<DateRange, HttpResponse> {
+        parse_date_range(&self.from, &self.to)
+    }
+}
+
+impl HistoryQuery {
+    fn date_range(&self) -> Result<DateRange, HttpResponse> {
+        parse_date_range(&self.from, &self.to)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct GalaxyQuery {
+    date: Option<String>,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetMonth {
+    #[default]
+    Current,
+    Last,
+}
+
+#[derive(Deserialize)]
+pub struct BudgetQuery {
+    #[serde(default)]
+    month: BudgetMonth,
+}
 
-pub async fn empires_impl(model_custodian: Data<ModelCustodian>) -> impl Responder {
+pub async fn empires_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+) -> impl Responder {
     let names = model_custodian
         .get_ref()
         .clone()
@@ -11,3 +90,953 @@ pub async fn empires_impl(model_custodian: Data<ModelCustodian>) -> impl Respond
 
     HttpResponse::Ok().json(names)
 }
+
+pub async fn empire_resources_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<ResourcesQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+    let classes: Vec<ResourceClass> = match &query.resource {
+        Some(resource) => match ResourceClass::from_str(resource) {
+            Ok(class) => vec![class],
+            Err(error) => return HttpResponse::BadRequest().body(error.to_string()),
+        },
+        None => ResourceClass::iter().collect(),
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    let mut resources = HashMap::new();
+    for class in classes {
+        let series = history
+            .iter()
+            .map(|(_, empire)| empire.resources.get(&class))
+            .collect();
+        resources.insert(class, series);
+    }
+
+    HttpResponse::Ok().json(ResourceSeriesDto {
+        empire: name.into_inner(),
+        dates: history.iter().map(|(date, _)| date.to_string()).collect(),
+        resources,
+    })
+}
+
+pub async fn empire_budget_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<BudgetQuery>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_empire_history(&name, None, None)
+        .await
+        .expect("Could not get empire history");
+
+    let (date, empire) = match history.last() {
+        Some(latest) => latest,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    let mut resources: HashMap<ResourceClass, ResourceBudgetDto> = HashMap::new();
+    for (component, map) in get_budget_maps(&empire.budget, query.month) {
+        for (class, contributors) in map.iter() {
+            let resource = resources.entry(class.clone()).or_default();
+            resource.totals.insert(
+                component.clone(),
+                contributors.iter().map(|(_, amount)| amount).sum(),
+            );
+            resource
+                .contributors
+                .insert(component.clone(), contributors.clone());
+        }
+    }
+
+    HttpResponse::Ok().json(BudgetDto {
+        empire: name.into_inner(),
+        date: date.to_string(),
+        resources,
+    })
+}
+
+pub async fn empire_planets_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    HttpResponse::Ok().json(ColoniesDto {
+        empire: name.into_inner(),
+        snapshots: history
+            .iter()
+            .map(|(date, empire)| ColonySnapshotDto {
+                date: date.to_string(),
+                planets: empire.planets.iter().map(get_planet_dto).collect(),
+            })
+            .collect(),
+    })
+}
+
+pub async fn empire_demographics_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    HttpResponse::Ok().json(DemographicsSeriesDto {
+        empire: name.into_inner(),
+        dates: history.iter().map(|(date, _)| date.to_string()).collect(),
+        species: get_pop_series(&history, |empire| &empire.demographics.species),
+        jobs: get_pop_series(&history, |empire| &empire.demographics.jobs),
+        strata: get_pop_series(&history, |empire| &empire.demographics.strata),
+        ethics: get_pop_series(&history, |empire| &empire.demographics.ethics),
+        happiness: history
+            .iter()
+            .map(|(_, empire)| empire.demographics.happiness)
+            .collect(),
+    })
+}
+
+pub async fn empire_military_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    let mut fleets: Vec<FleetSeriesDto> = vec![];
+    for (index, (_, empire)) in history.iter().enumerate() {
+        for fleet in empire.fleets.iter() {
+            let position = match fleets.iter().position(|series| series.id == fleet.id) {
+                Some(position) => position,
+                None => {
+                    fleets.push(FleetSeriesDto {
+                        id: fleet.id,
+                        name: String::new(),
+                        military_power: vec![None; history.len()],
+                    });
+                    fleets.len() - 1
+                }
+            };
+            fleets[position].name = fleet.name.clone();
+            fleets[position].military_power[index] = Some(fleet.military_power);
+        }
+    }
+
+    HttpResponse::Ok().json(MilitarySeriesDto {
+        empire: name.into_inner(),
+        dates: history.iter().map(|(date, _)| date.to_string()).collect(),
+        total: history
+            .iter()
+            .map(|(_, empire)| empire.fleets.iter().map(|fleet| fleet.military_power).sum())
+            .collect(),
+        fleets,
+    })
+}
+
+pub async fn empire_leaders_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    let mut events = vec![];
+    for window in history.windows(2) {
+        let (_, previous) = &window[0];
+        let (date, current) = &window[1];
+        for leader in current.leaders.iter() {
+            match previous
+                .leaders
+                .iter()
+                .find(|previous| previous.id == leader.id)
+            {
+                None => events.push(get_leader_event(date, LeaderEventKind::Recruited, leader)),
+                Some(previous) if leader.level > previous.level => {
+                    events.push(get_leader_event(date, LeaderEventKind::LevelUp, leader))
+                }
+                Some(_) => {}
+            }
+        }
+        for leader in previous.leaders.iter() {
+            if !current
+                .leaders
+                .iter()
+                .any(|current| current.id == leader.id)
+            {
+                events.push(get_leader_event(date, LeaderEventKind::Removed, leader));
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(LeaderEventsDto {
+        empire: name.into_inner(),
+        events,
+    })
+}
+
+fn get_leader_event(
+    date: &StellarisDate,
+    kind: LeaderEventKind,
+    leader: &LeaderData,
+) -> LeaderEventDto {
+    LeaderEventDto {
+        date: date.to_string(),
+        kind,
+        id: leader.id,
+        name: leader.name.clone(),
+        class: leader.class.clone(),
+        level: leader.level,
+    }
+}
+
+pub async fn empire_technologies_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_empire_history(&name, None, None)
+        .await
+        .expect("Could not get empire history");
+
+    let mut first_seen: HashMap<&str, StellarisDate> = HashMap::new();
+    for (date, empire) in history.iter() {
+        for technology in empire.tech.researched.keys() {
+            first_seen.entry(technology).or_insert(*date);
+        }
+    }
+
+    let mut technologies: Vec<(&str, StellarisDate)> = first_seen.into_iter().collect();
+    technologies.sort_by(|(a, a_date), (b, b_date)| a_date.cmp(b_date).then(a.cmp(b)));
+
+    HttpResponse::Ok().json(TechnologiesDto {
+        empire: name.into_inner(),
+        technologies: technologies
+            .into_iter()
+            .map(|(technology, date)| TechnologyDto {
+                technology: String::from(technology),
+                first_seen: date.to_string(),
+            })
+            .collect(),
+    })
+}
+
+pub async fn empire_identity_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_empire_history(&name, from, to)
+        .await
+        .expect("Could not get empire history");
+
+    let mut snapshots: Vec<IdentitySnapshotDto> = vec![];
+    let mut changes = vec![];
+    for (index, (date, empire)) in history.iter().enumerate() {
+        let identity = &empire.identity;
+        if let Some((_, previous)) = index.checked_sub(1).map(|index| &history[index]) {
+            changes.extend(get_identity_changes(date, &previous.identity, identity));
+        }
+
+        let previous_policies = snapshots.last().map(|snapshot| &snapshot.policies);
+        let policies = identity
+            .policies
+            .iter()
+            .map(|policy| {
+                let adopted = match policy.date {
+                    Some(adopted) => adopted.to_string(),
+                    None => previous_policies
+                        .and_then(|policies| {
+                            policies.iter().find(|previous| {
+                                previous.policy == policy.policy
+                                    && previous.selected == policy.selected
+                            })
+                        })
+                        .map_or_else(|| date.to_string(), |previous| previous.adopted.clone()),
+                };
+                PolicyDto {
+                    policy: policy.policy.clone(),
+                    selected: policy.selected.clone(),
+                    adopted,
+                }
+            })
+            .collect();
+
+        snapshots.push(IdentitySnapshotDto {
+            date: date.to_string(),
+            government: identity.government.clone(),
+            authority: identity.authority.clone(),
+            civics: identity.civics.clone(),
+            ethics: identity.ethics.clone(),
+            origin: identity.origin.clone(),
+            policies,
+        });
+    }
+
+    HttpResponse::Ok().json(IdentityDto {
+        empire: name.into_inner(),
+        snapshots,
+        changes,
+    })
+}
+
+fn get_identity_changes(
+    date: &StellarisDate,
+    previous: &EmpireIdentity,
+    current: &EmpireIdentity,
+) -> Vec<IdentityChangeDto> {
+    let change = |kind, policy: Option<&str>, previous: Option<&str>, current: Option<&str>| {
+        IdentityChangeDto {
+            date: date.to_string(),
+            kind,
+            policy: policy.map(String::from),
+            previous: previous.map(String::from),
+            current: current.map(String::from),
+        }
+    };
+
+    let mut changes = vec![];
+    for (kind, previous, current) in [
+        (
+            IdentityChangeKind::Government,
+            &previous.government,
+            &current.government,
+        ),
+        (
+            IdentityChangeKind::Authority,
+            &previous.authority,
+            &current.authority,
+        ),
+        (
+            IdentityChangeKind::Origin,
+            &previous.origin,
+            &current.origin,
+        ),
+    ] {
+        if previous != current {
+            changes.push(change(kind, None, Some(previous), Some(current)));
+        }
+    }
+
+    for (kind, previous, current) in [
+        (IdentityChangeKind::Civic, &previous.civics, &current.civics),
+        (IdentityChangeKind::Ethic, &previous.ethics, &current.ethics),
+    ] {
+        for name in previous.iter().filter(|name| !current.contains(name)) {
+            changes.push(change(kind, None, Some(name), None));
+        }
+        for name in current.iter().filter(|name| !previous.contains(name)) {
+            changes.push(change(kind, None, None, Some(name)));
+        }
+    }
+
+    for policy in current.policies.iter() {
+        match previous
+            .policies
+            .iter()
+            .find(|previous| previous.policy == policy.policy)
+        {
+            Some(previous) if previous.selected == policy.selected => {}
+            previous => changes.push(change(
+                IdentityChangeKind::Policy,
+                Some(&policy.policy),
+                previous.map(|previous| previous.selected.as_str()),
+                Some(&policy.selected),
+            )),
+        }
+    }
+    for policy in previous.policies.iter().filter(|previous| {
+        !current
+            .policies
+            .iter()
+            .any(|current| current.policy == previous.policy)
+    }) {
+        changes.push(change(
+            IdentityChangeKind::Policy,
+            Some(&policy.policy),
+            Some(&policy.selected),
+            None,
+        ));
+    }
+    changes
+}
+
+pub async fn empire_trade_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_current_campaign_history()
+        .await
+        .expect("Could not get campaign history");
+
+    let snapshots = history
+        .iter()
+        .filter(|data_point| from.iter().all(|from| data_point.date >= *from))
+        .filter(|data_point| to.iter().all(|to| data_point.date <= *to))
+        .filter_map(|data_point| {
+            let empire = data_point
+                .empires
+                .iter()
+                .find(|empire| empire.name == *name)?;
+            let owned: Vec<_> = data_point
+                .systems
+                .iter()
+                .filter(|system| system.owner.as_deref() == Some(name.as_str()))
+                .collect();
+            Some(TradeSnapshotDto {
+                date: data_point.date.to_string(),
+                trade_value: empire.trade_value,
+                collected: owned.iter().map(|system| system.trade.collected).sum(),
+                piracy_losses: owned
+                    .iter()
+                    .map(|system| system.trade.piracy.min(system.trade.throughput))
+                    .sum(),
+                hubs: owned
+                    .iter()
+                    .filter(|system| !system.trade.collected_from.is_empty())
+                    .map(|system| TradeHubDto {
+                        system: system.id,
+                        collected: system.trade.collected,
+                        collected_from: system.trade.collected_from.clone(),
+                        piracy: system.trade.piracy,
+                    })
+                    .collect(),
+                routes: data_point
+                    .trade_routes
+                    .iter()
+                    .filter(|route| route.owner == *name)
+                    .map(|route| TradeRouteDto {
+                        id: route.id,
+                        from: route.from,
+                        to: route.to,
+                        delivered: route.delivered,
+                        path: route.path.clone(),
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+
+    HttpResponse::Ok().json(TradeDto {
+        empire: name.into_inner(),
+        snapshots,
+    })
+}
+
+pub async fn empire_megastructures_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    let (from, to) = match query.date_range() {
+        Ok(range) => range,
+        Err(response) => return response,
+    };
+
+    let history = model_custodian
+        .get_current_campaign_history()
+        .await
+        .expect("Could not get campaign history");
+
+    // Each megastructure stage is its own type ("dyson_sphere_1", "dyson_sphere_2", ...), so a
+    // stage is completed in the first snapshot its type shows up in.
+    let mut megastructures: Vec<MegastructureDto> = vec![];
+    let range = history
+        .iter()
+        .filter(|data_point| from.iter().all(|from| data_point.date >= *from))
+        .filter(|data_point| to.iter().all(|to| data_point.date <= *to));
+    for (index, data_point) in range.enumerate() {
+        let completed = Some(data_point.date.to_string()).filter(|_| index > 0);
+        for megastructure in data_point
+            .megastructures
+            .iter()
+            .filter(|megastructure| megastructure.owner.as_deref() == Some(name.as_str()))
+        {
+            let stage = MegastructureStageDto {
+                stage: megastructure.megastructure_type.clone(),
+                completed: completed.clone(),
+            };
+            match megastructures
+                .iter_mut()
+                .find(|previous| previous.id == megastructure.id)
+            {
+                None => megastructures.push(MegastructureDto {
+                    id: megastructure.id,
+                    system: megastructure.system,
+                    megastructure_type: megastructure.megastructure_type.clone(),
+                    stages: vec![stage],
+                }),
+                Some(previous)
+                    if previous.megastructure_type != megastructure.megastructure_type =>
+                {
+                    previous.megastructure_type = megastructure.megastructure_type.clone();
+                    previous.stages.push(stage);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(MegastructuresDto {
+        empire: name.into_inner(),
+        megastructures,
+    })
+}
+
+pub async fn campaign_wars_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_campaign_history(&name)
+        .await
+        .expect("Could not get campaign history");
+
+    let mut wars: Vec<WarDto> = vec![];
+    for (index, data_point) in history.iter().enumerate() {
+        let next = history.get(index + 1);
+        for war in data_point.wars.iter() {
+            let position = match wars.iter().position(|dto| dto.id == war.id) {
+                Some(position) => position,
+                None => {
+                    wars.push(WarDto {
+                        id: war.id,
+                        name: String::new(),
+                        start_date: war.start_date.to_string(),
+                        end_date: None,
+                        active: false,
+                        attackers: vec![],
+                        defenders: vec![],
+                        attacker_war_goal: String::new(),
+                        defender_war_goal: None,
+                        dates: vec![],
+                        attacker_exhaustion: vec![],
+                        defender_exhaustion: vec![],
+                    });
+                    wars.len() - 1
+                }
+            };
+            let dto = &mut wars[position];
+            dto.name = war.name.clone();
+            dto.attackers = war.attackers.clone();
+            dto.defenders = war.defenders.clone();
+            dto.attacker_war_goal = war.attacker_war_goal.clone();
+            dto.defender_war_goal = war.defender_war_goal.clone();
+            dto.dates.push(data_point.date.to_string());
+            dto.attacker_exhaustion.push(war.attacker_exhaustion);
+            dto.defender_exhaustion.push(war.defender_exhaustion);
+            dto.active = next.is_none();
+            dto.end_date = next
+                .filter(|next| next.wars.iter().all(|next_war| next_war.id != war.id))
+                .map(|next| next.date.to_string());
+        }
+    }
+
+    let mut truces: Vec<TruceDto> = vec![];
+    for (index, data_point) in history.iter().enumerate() {
+        let next = history.get(index + 1);
+        for truce in data_point.truces.iter() {
+            let dto = TruceDto {
+                id: truce.id,
+                name: truce.name.clone(),
+                start_date: truce.start_date.to_string(),
+                end_date: next
+                    .filter(|next| {
+                        next.truces
+                            .iter()
+                            .all(|next_truce| next_truce.id != truce.id)
+                    })
+                    .map(|next| next.date.to_string()),
+                active: next.is_none(),
+                truce_type: truce.truce_type.clone(),
+                countries: truce.countries.clone(),
+            };
+            match truces.iter_mut().find(|existing| existing.id == truce.id) {
+                Some(existing) => *existing = dto,
+                None => truces.push(dto),
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(WarsDto {
+        campaign: name.into_inner(),
+        wars,
+        truces,
+    })
+}
+
+pub async fn campaign_diplomacy_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_campaign_history(&name)
+        .await
+        .expect("Could not get campaign history");
+
+    HttpResponse::Ok().json(DiplomacyDto {
+        campaign: name.into_inner(),
+        snapshots: history
+            .into_iter()
+            .map(|data_point| DiplomacySnapshotDto {
+                date: data_point.date.to_string(),
+                federations: data_point
+                    .federations
+                    .into_iter()
+                    .map(|federation| FederationDto {
+                        name: federation.name,
+                        federation_type: federation.federation_type,
+                        level: federation.level,
+                        cohesion: federation.cohesion,
+                        leader: federation.leader,
+                        members: federation.members,
+                    })
+                    .collect(),
+                galactic_community: GalacticCommunityDto {
+                    members: data_point.galactic_community.members,
+                    council: data_point.galactic_community.council,
+                    resolutions: data_point.galactic_community.resolutions,
+                    voting: data_point.galactic_community.voting,
+                },
+            })
+            .collect(),
+    })
+}
+
+pub async fn campaign_relations_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_campaign_history(&name)
+        .await
+        .expect("Could not get campaign history");
+
+    let mut countries: Vec<String> = vec![];
+    for relation in history
+        .iter()
+        .flat_map(|data_point| data_point.relations.iter())
+    {
+        for country in [&relation.owner, &relation.country] {
+            if !countries.contains(country) {
+                countries.push(country.clone());
+            }
+        }
+    }
+
+    let snapshots = history
+        .iter()
+        .map(|data_point| {
+            let mut matrix = vec![vec![None; countries.len()]; countries.len()];
+            for relation in data_point.relations.iter() {
+                let owner = countries.iter().position(|c| *c == relation.owner);
+                let country = countries.iter().position(|c| *c == relation.country);
+                if let (Some(owner), Some(country)) = (owner, country) {
+                    matrix[owner][country] = Some(RelationDto {
+                        opinion: relation.opinion,
+                        trust: relation.trust,
+                        treaties: relation.treaties.clone(),
+                        rival: relation.rival,
+                        borders: relation.borders,
+                    });
+                }
+            }
+            RelationSnapshotDto {
+                date: data_point.date.to_string(),
+                matrix,
+            }
+        })
+        .collect();
+
+    HttpResponse::Ok().json(RelationMatrixDto {
+        campaign: name.into_inner(),
+        countries,
+        snapshots,
+    })
+}
+
+pub async fn campaign_market_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    name: Path<String>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_campaign_history(&name)
+        .await
+        .expect("Could not get campaign history");
+
+    let mut prices: HashMap<ResourceClass, Vec<Option<f64>>> = HashMap::new();
+    let mut empires: Vec<TradeVolumeSeriesDto> = vec![];
+    for (index, data_point) in history.iter().enumerate() {
+        let market = &data_point.market;
+        for resource in market.resources.iter() {
+            prices
+                .entry(resource.resource.clone())
+                .or_insert_with(|| vec![None; history.len()])[index] = Some(resource.price);
+        }
+
+        for trade in market.trades.iter() {
+            let price = market
+                .resources
+                .iter()
+                .find(|resource| resource.resource == trade.resource)
+                .map_or(0.0, |resource| resource.price);
+            let position = match empires
+                .iter()
+                .position(|series| series.empire == trade.country)
+            {
+                Some(position) => position,
+                None => {
+                    empires.push(TradeVolumeSeriesDto {
+                        empire: trade.country.clone(),
+                        bought: vec![0.0; history.len()],
+                        sold: vec![0.0; history.len()],
+                        net: vec![0.0; history.len()],
+                    });
+                    empires.len() - 1
+                }
+            };
+            let series = &mut empires[position];
+            series.bought[index] += trade.bought * price;
+            series.sold[index] += trade.sold * price;
+            series.net[index] = series.sold[index] - series.bought[index];
+        }
+    }
+
+    HttpResponse::Ok().json(MarketDto {
+        campaign: name.into_inner(),
+        dates: history
+            .iter()
+            .map(|data_point| data_point.date.to_string())
+            .collect(),
+        prices,
+        empires,
+    })
+}
+
+pub async fn galaxy_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+    query: Query<GalaxyQuery>,
+) -> impl Responder {
+    let date = match parse_date(&query.date) {
+        Ok(date) => date,
+        Err(error) => return HttpResponse::BadRequest().body(error.to_string()),
+    };
+
+    let history = model_custodian
+        .get_current_campaign_history()
+        .await
+        .expect("Could not get campaign history");
+    let data_point = match history
+        .iter()
+        .rev()
+        .find(|data_point| date.iter().all(|date| data_point.date <= *date))
+    {
+        Some(data_point) => data_point,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    // Each lane is listed by both of its systems.
+    let hyperlanes: BTreeSet<(i64, i64)> = data_point
+        .systems
+        .iter()
+        .flat_map(|system| {
+            system
+                .hyperlanes
+                .iter()
+                .map(move |destination| (system.id.min(*destination), system.id.max(*destination)))
+        })
+        .collect();
+
+    HttpResponse::Ok().json(GalaxyDto {
+        date: data_point.date.to_string(),
+        systems: data_point
+            .systems
+            .iter()
+            .map(|system| SystemDto {
+                id: system.id,
+                name: system.name.clone(),
+                x: system.x,
+                y: system.y,
+                star_class: system.star_class.clone(),
+                owner: system.owner.clone(),
+            })
+            .collect(),
+        hyperlanes: hyperlanes
+            .into_iter()
+            .map(|(from, to)| HyperlaneDto { from, to })
+            .collect(),
+    })
+}
+
+pub async fn campaign_leaderboard_impl<B: DataCoreBackend>(
+    model_custodian: Data<ModelCustodian<B>>,
+) -> impl Responder {
+    let history = model_custodian
+        .get_current_campaign_history()
+        .await
+        .expect("Could not get campaign history");
+    let campaign = match history.last() {
+        Some(data_point) => data_point.campaign_name.clone(),
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    let snapshots = history
+        .iter()
+        .map(|data_point| {
+            let mut empires: Vec<LeaderboardEntryDto> = data_point
+                .empires
+                .iter()
+                .map(|empire| LeaderboardEntryDto {
+                    name: empire.name.clone(),
+                    victory_rank: empire.victory_rank,
+                    victory_score: empire.victory_score,
+                    military_power: empire.military_power,
+                    economy_power: empire.economy_power,
+                    tech_power: empire.tech_power,
+                    empire_size: empire.empire_size,
+                    fleet_size: empire.fleet_size,
+                    starbase_capacity: empire.starbase_capacity,
+                })
+                .collect();
+            empires.sort_by(|a, b| {
+                b.victory_score
+                    .partial_cmp(&a.victory_score)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.victory_rank.cmp(&b.victory_rank))
+            });
+            LeaderboardSnapshotDto {
+                date: data_point.date.to_string(),
+                empires,
+            }
+        })
+        .collect();
+
+    HttpResponse::Ok().json(LeaderboardDto {
+        campaign,
+        snapshots,
+    })
+}
+
+pub async fn updates_impl(broadcaster: Data<Mutex<Broadcaster>>) -> impl Responder {
+    let client = broadcaster.lock().unwrap().new_client();
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .streaming(client.map(Ok::<_, actix_web::Error>))
+}
+
+fn get_budget_maps(budget: &Budget, month: BudgetMonth) -> Vec<(BudgetComponent, &BudgetMap)> {
+    match month {
+        BudgetMonth::Current => vec![
+            (BudgetComponent::Income, &budget.income),
+            (BudgetComponent::Expenses, &budget.expense),
+            (BudgetComponent::Balance, &budget.balance),
+        ],
+        BudgetMonth::Last => vec![
+            (BudgetComponent::Income, &budget.income_last_month),
+            (BudgetComponent::Expenses, &budget.expense_last_month),
+            (BudgetComponent::Balance, &budget.balance_last_month),
+        ],
+    }
+}
+
+fn get_pop_series<F>(history: &EmpireHistory, counts: F) -> HashMap<String, Vec<i64>>
+where
+    F: Fn(&EmpireData) -> &HashMap<String, i64>,
+{
+    let mut series: HashMap<String, Vec<i64>> = HashMap::new();
+    for (index, (_, empire)) in history.iter().enumerate() {
+        for (name, pops) in counts(empire).iter() {
+            series
+                .entry(name.clone())
+                .or_insert_with(|| vec![0; history.len()])[index] = *pops;
+        }
+    }
+    series
+}
+
+fn get_planet_dto(planet: &PlanetData) -> PlanetDto {
+    PlanetDto {
+        id: planet.id,
+        name: planet.name.clone(),
+        class: planet.class.clone(),
+        size: planet.size,
+        owner: planet.owner,
+        controller: planet.controller,
+        pops: planet.pops,
+        districts: planet.districts.clone(),
+        buildings: planet.buildings.clone(),
+        stability: planet.stability,
+        amenities: planet.amenities,
+        housing: planet.housing,
+        crime: planet.crime,
+    }
+}
+
+fn parse_date(date: &Option<String>) -> anyhow::Result<Option<StellarisDate>> {
+    match date {
+        Some(date) => Ok(Some(StellarisDate::from_str(date)?)),
+        None => Ok(None),
+    }
+}
+
+fn parse_date_range(from: &Option<String>, to: &Option<String>) -> Result<DateRange, HttpResponse> {
+    let bad_request = |error: anyhow::Error| HttpResponse::BadRequest().body(error.to_string());
+    Ok((
+        parse_date(from).map_err(bad_request)?,
+        parse_date(to).map_err(bad_request)?,
+    ))
+}
@@ -1,6 +1,11 @@
 mod implementation;
 
 mod prod;
-pub use prod::empires;
+pub use prod::{
+    campaign_diplomacy, campaign_leaderboard, campaign_market, campaign_relations, campaign_wars,
+    empire_budget, empire_demographics, empire_identity, empire_leaders, empire_megastructures,
+    empire_military, empire_planets, empire_resources, empire_technologies, empire_trade, empires,
+    galaxy, updates,
+};
 #[cfg(test)]
 mod test;
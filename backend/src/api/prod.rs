@@ -1,9 +1,167 @@
-use actix_web::{get, web::Data, Responder};
+use std::sync::Mutex;
+
+use actix_web::{
+    get,
+    web::{Data, Path, Query},
+    Responder,
+};
+use data_core::DataCore;
 use data_model::ModelCustodian;
 
-use crate::api::implementation::empires_impl;
+use crate::{
+    api::implementation::{
+        campaign_diplomacy_impl, campaign_leaderboard_impl, campaign_market_impl,
+        campaign_relations_impl, campaign_wars_impl, empire_budget_impl, empire_demographics_impl,
+        empire_identity_impl, empire_leaders_impl, empire_megastructures_impl,
+        empire_military_impl, empire_planets_impl, empire_resources_impl, empire_technologies_impl,
+        empire_trade_impl, empires_impl, galaxy_impl, updates_impl, BudgetQuery, GalaxyQuery,
+        HistoryQuery, ResourcesQuery,
+    },
+    broadcaster::Broadcaster,
+};
 
 #[get("/empires")]
-pub async fn empires(model_custodian: Data<ModelCustodian>) -> impl Responder {
+pub async fn empires(model_custodian: Data<ModelCustodian<DataCore>>) -> impl Responder {
     empires_impl(model_custodian).await
 }
+
+#[get("/empires/{name}/resources")]
+pub async fn empire_resources(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<ResourcesQuery>,
+) -> impl Responder {
+    empire_resources_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/budget")]
+pub async fn empire_budget(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<BudgetQuery>,
+) -> impl Responder {
+    empire_budget_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/planets")]
+pub async fn empire_planets(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_planets_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/demographics")]
+pub async fn empire_demographics(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_demographics_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/military")]
+pub async fn empire_military(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_military_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/leaders")]
+pub async fn empire_leaders(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_leaders_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/technologies")]
+pub async fn empire_technologies(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    empire_technologies_impl(model_custodian, name).await
+}
+
+#[get("/empires/{name}/identity")]
+pub async fn empire_identity(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_identity_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/trade")]
+pub async fn empire_trade(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_trade_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/megastructures")]
+pub async fn empire_megastructures(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_megastructures_impl(model_custodian, name, query).await
+}
+
+#[get("/campaigns/{name}/wars")]
+pub async fn campaign_wars(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_wars_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/diplomacy")]
+pub async fn campaign_diplomacy(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_diplomacy_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/relations")]
+pub async fn campaign_relations(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_relations_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/market")]
+pub async fn campaign_market(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_market_impl(model_custodian, name).await
+}
+
+#[get("/galaxy")]
+pub async fn galaxy(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+    query: Query<GalaxyQuery>,
+) -> impl Responder {
+    galaxy_impl(model_custodian, query).await
+}
+
+#[get("/campaign/leaderboard")]
+pub async fn campaign_leaderboard(
+    model_custodian: Data<ModelCustodian<DataCore>>,
+) -> impl Responder {
+    campaign_leaderboard_impl(model_custodian).await
+}
+
+#[get("/updates")]
+pub async fn updates(broadcaster: Data<Mutex<Broadcaster>>) -> impl Responder {
+    updates_impl(broadcaster).await
+}
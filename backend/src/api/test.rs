@@ -1,23 +1,194 @@
-use actix_web::{get, web::Data, Responder};
+use actix_web::{
+    get,
+    web::{Data, Path, Query},
+    Responder,
+};
+use data_core_mock::MockDataCore;
 use data_model::ModelCustodian;
 
-use crate::api::implementation::empires_impl;
+use crate::api::implementation::{
+    campaign_diplomacy_impl, campaign_leaderboard_impl, campaign_market_impl,
+    campaign_relations_impl, campaign_wars_impl, empire_budget_impl, empire_demographics_impl,
+    empire_identity_impl, empire_leaders_impl, empire_megastructures_impl, empire_military_impl,
+    empire_planets_impl, empire_resources_impl, empire_technologies_impl, empire_trade_impl,
+    empires_impl, galaxy_impl, BudgetQuery, GalaxyQuery, HistoryQuery, ResourcesQuery,
+};
 
 #[get("/empires")]
-pub async fn empires_test(model_custodian: Data<ModelCustodian>) -> impl Responder {
+pub async fn empires_test(model_custodian: Data<ModelCustodian<MockDataCore>>) -> impl Responder {
     empires_impl(model_custodian).await
 }
 
+#[get("/empires/{name}/resources")]
+pub async fn empire_resources_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<ResourcesQuery>,
+) -> impl Responder {
+    empire_resources_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/budget")]
+pub async fn empire_budget_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<BudgetQuery>,
+) -> impl Responder {
+    empire_budget_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/planets")]
+pub async fn empire_planets_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_planets_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/demographics")]
+pub async fn empire_demographics_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_demographics_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/military")]
+pub async fn empire_military_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_military_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/leaders")]
+pub async fn empire_leaders_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_leaders_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/technologies")]
+pub async fn empire_technologies_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    empire_technologies_impl(model_custodian, name).await
+}
+
+#[get("/empires/{name}/identity")]
+pub async fn empire_identity_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_identity_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/trade")]
+pub async fn empire_trade_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_trade_impl(model_custodian, name, query).await
+}
+
+#[get("/empires/{name}/megastructures")]
+pub async fn empire_megastructures_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+    query: Query<HistoryQuery>,
+) -> impl Responder {
+    empire_megastructures_impl(model_custodian, name, query).await
+}
+
+#[get("/campaigns/{name}/wars")]
+pub async fn campaign_wars_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_wars_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/diplomacy")]
+pub async fn campaign_diplomacy_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_diplomacy_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/relations")]
+pub async fn campaign_relations_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_relations_impl(model_custodian, name).await
+}
+
+#[get("/campaigns/{name}/market")]
+pub async fn campaign_market_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    name: Path<String>,
+) -> impl Responder {
+    campaign_market_impl(model_custodian, name).await
+}
+
+#[get("/galaxy")]
+pub async fn galaxy_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+    query: Query<GalaxyQuery>,
+) -> impl Responder {
+    galaxy_impl(model_custodian, query).await
+}
+
+#[get("/campaign/leaderboard")]
+pub async fn campaign_leaderboard_test(
+    model_custodian: Data<ModelCustodian<MockDataCore>>,
+) -> impl Responder {
+    campaign_leaderboard_impl(model_custodian).await
+}
+
 #[cfg(test)]
 mod api_tests {
 
-    use std::sync::mpsc::channel;
+    use std::{collections::HashMap, str::FromStr, sync::mpsc::channel, thread, time::Duration};
 
     use actix_web::{body::Body, get, test, web::Data, App, Responder};
-    use data_model::{Budget, CustodianMsg, EmpireData, ModelCustodian, ModelDataPoint, Resources};
+    use data_core::DuplicatePolicy;
+    use data_core_mock::MockDataCore;
+    use data_model::{
+        Budget, CustodianMsg, Demographics, EmpireData, EmpireIdentity, FederationData, FleetData,
+        GalacticCommunity, LeaderData, MarketData, MarketResourceData, MarketTradeData,
+        MegastructureData, ModelCustodian, ModelDataPoint, PlanetData, PolicyData, RelationData,
+        Resources, StellarisDate, SystemData, TechStatus, TradeHubData, TradeRouteData, TruceData,
+        WarData,
+    };
     use serde_json::json;
+    use stellarust::dto::{
+        BudgetComponent, BudgetDto, ColoniesDto, DemographicsSeriesDto, DiplomacyDto,
+        FederationDto, FleetSeriesDto, GalacticCommunityDto, GalaxyDto, HyperlaneDto,
+        IdentityChangeDto, IdentityChangeKind, IdentityDto, LeaderEventDto, LeaderEventKind,
+        LeaderEventsDto, LeaderboardDto, MarketDto, MegastructureDto, MegastructureStageDto,
+        MegastructuresDto, MilitarySeriesDto, PolicyDto, RelationDto, RelationMatrixDto,
+        ResourceClass, ResourceSeriesDto, SystemDto, TechnologiesDto, TechnologyDto, TradeDto,
+        TradeHubDto, TradeRouteDto, TradeVolumeSeriesDto, TruceDto, WarDto, WarsDto,
+    };
 
-    use super::empires_test;
+    use super::{
+        campaign_diplomacy_test, campaign_leaderboard_test, campaign_market_test,
+        campaign_relations_test, campaign_wars_test, empire_budget_test, empire_demographics_test,
+        empire_identity_test, empire_leaders_test, empire_megastructures_test,
+        empire_military_test, empire_planets_test, empire_resources_test, empire_technologies_test,
+        empire_trade_test, empires_test, galaxy_test,
+    };
+    use crate::{api::updates, broadcaster::Broadcaster};
 
     #[actix_rt::test]
     async fn test_empires__from_custodian__returns_list_of_empire_names() {
@@ -26,17 +197,31 @@ mod api_tests {
         let (sender, receiver) = channel();
 
         sender
-            .send(CustodianMsg::Data(ModelDataPoint {
+            .send(CustodianMsg::Data(Box::new(ModelDataPoint {
                 campaign_name: String::new(),
+                date: StellarisDate::new(2200, 1, 1).unwrap(),
                 empires: vec![EmpireData {
                     name: String::from("NAME"),
-                    budget: Budget::default(),
-                    resources: Resources::default(),
+                    ..EmpireData::default()
                 }],
-            }))
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            })))
             .unwrap();
 
-        let custodian = ModelCustodian::create(receiver);
+        let custodian =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
 
         let mut app = test::init_service(
             App::new()
@@ -55,4 +240,1745 @@ mod api_tests {
         assert!(resp.status().is_success());
         assert_eq!(&Body::from(json!(expected_empire_names.clone())), body);
     }
+
+    #[actix_rt::test]
+    async fn test_empire_resources__given_range_and_resource__returns_series_in_range() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_resources_custodian().await))
+                .service(empire_resources_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/resources?from=2200.02.01&to=2200.03.01&resource=Energy")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: ResourceSeriesDto = test::read_body_json(resp).await;
+        let mut resources = HashMap::new();
+        resources.insert(ResourceClass::Energy, vec![200f64, 300f64]);
+        assert_eq!(
+            actual,
+            ResourceSeriesDto {
+                empire: String::from("NAME"),
+                dates: vec![String::from("2200.02.01"), String::from("2200.03.01")],
+                resources,
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_resources__given_no_filters__returns_every_resource_for_every_snapshot() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_resources_custodian().await))
+                .service(empire_resources_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/resources")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: ResourceSeriesDto = test::read_body_json(resp).await;
+        assert_eq!(actual.dates.len(), 4);
+        assert_eq!(actual.resources.len(), 16);
+        assert_eq!(
+            actual.resources[&ResourceClass::Minerals],
+            vec![50f64, 50f64, 50f64, 50f64]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_resources__given_malformed_query__returns_bad_request() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_resources_custodian().await))
+                .service(empire_resources_test),
+        )
+        .await;
+
+        for uri in [
+            "/empires/NAME/resources?from=2200.13.01",
+            "/empires/NAME/resources?resource=Gold",
+        ] {
+            let req = test::TestRequest::with_header("content-type", "application/json")
+                .uri(uri)
+                .to_request();
+
+            let resp = test::call_service(&mut app, req).await;
+
+            assert!(resp.status().is_client_error());
+        }
+    }
+
+    async fn get_resources_custodian() -> ModelCustodian<MockDataCore> {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec!["2200.01.01", "2200.02.01", "2200.03.01", "2200.04.01"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, date)| ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str(date).unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    budget: Budget::default(),
+                    resources: Resources {
+                        energy: 100f64 * (index + 1) as f64,
+                        minerals: 50f64,
+                        ..Resources::default()
+                    },
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            })
+            .collect();
+
+        ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_budget__given_no_month__returns_current_month_of_latest_snapshot() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_budget_custodian().await))
+                .service(empire_budget_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/budget")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: BudgetDto = test::read_body_json(resp).await;
+        assert_eq!(actual.empire, "NAME");
+        assert_eq!(actual.date, "2200.02.01");
+        assert_eq!(actual.resources.len(), 1);
+        let energy = &actual.resources[&ResourceClass::Energy];
+        assert_eq!(
+            energy.contributors[&BudgetComponent::Income],
+            vec![(String::from("a"), 10f64), (String::from("b"), 5f64)]
+        );
+        assert_eq!(energy.totals[&BudgetComponent::Income], 15f64);
+        assert_eq!(energy.totals[&BudgetComponent::Expenses], 3f64);
+        assert_eq!(energy.totals[&BudgetComponent::Balance], 12f64);
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_budget__given_last_month__returns_last_month_of_latest_snapshot() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_budget_custodian().await))
+                .service(empire_budget_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/budget?month=last")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: BudgetDto = test::read_body_json(resp).await;
+        assert_eq!(actual.resources.len(), 1);
+        let minerals = &actual.resources[&ResourceClass::Minerals];
+        assert_eq!(
+            minerals.contributors[&BudgetComponent::Income],
+            vec![(String::from("d"), 7f64)]
+        );
+        assert_eq!(minerals.totals[&BudgetComponent::Income], 7f64);
+        assert!(!minerals.totals.contains_key(&BudgetComponent::Expenses));
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_budget__given_unknown_empire_or_month__returns_client_error() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_budget_custodian().await))
+                .service(empire_budget_test),
+        )
+        .await;
+
+        for uri in ["/empires/UNKNOWN/budget", "/empires/NAME/budget?month=next"] {
+            let req = test::TestRequest::with_header("content-type", "application/json")
+                .uri(uri)
+                .to_request();
+
+            let resp = test::call_service(&mut app, req).await;
+
+            assert!(resp.status().is_client_error());
+        }
+    }
+
+    async fn get_budget_custodian() -> ModelCustodian<MockDataCore> {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+
+        let mut budget = Budget::default();
+        budget.income.insert(
+            ResourceClass::Energy,
+            vec![(String::from("a"), 10f64), (String::from("b"), 5f64)],
+        );
+        budget
+            .expense
+            .insert(ResourceClass::Energy, vec![(String::from("c"), 3f64)]);
+        budget
+            .balance
+            .insert(ResourceClass::Energy, vec![(String::from("e"), 12f64)]);
+        budget
+            .income_last_month
+            .insert(ResourceClass::Minerals, vec![(String::from("d"), 7f64)]);
+
+        let snapshots = vec![
+            ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str("2200.01.01").unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            },
+            ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str("2200.02.01").unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    budget,
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            },
+        ];
+
+        ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_planets__given_range__returns_colonies_of_each_snapshot_in_range() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_planets_custodian().await))
+                .service(empire_planets_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/planets?from=2200.02.01")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: ColoniesDto = test::read_body_json(resp).await;
+        assert_eq!(actual.empire, "NAME");
+        assert_eq!(
+            actual
+                .snapshots
+                .iter()
+                .map(|snapshot| snapshot.date.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["2200.02.01", "2200.03.01"]
+        );
+        assert_eq!(
+            actual
+                .snapshots
+                .iter()
+                .map(|snapshot| snapshot.planets.len())
+                .collect::<Vec<usize>>(),
+            vec![2, 3]
+        );
+        let earth = &actual.snapshots[1].planets[0];
+        assert_eq!(earth.name, "Earth");
+        assert_eq!(earth.districts, vec![String::from("district_city")]);
+        assert_eq!(earth.pops, 3);
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_planets__given_malformed_date__returns_bad_request() {
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(get_planets_custodian().await))
+                .service(empire_planets_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/planets?to=2200.1.32")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_client_error());
+    }
+
+    async fn get_planets_custodian() -> ModelCustodian<MockDataCore> {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec!["2200.01.01", "2200.02.01", "2200.03.01"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, date)| ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str(date).unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    planets: (0..=index as i64)
+                        .map(|id| PlanetData {
+                            id,
+                            name: String::from(["Earth", "Mars", "Luna"][id as usize]),
+                            pops: index as i64 + 1,
+                            districts: vec![String::from("district_city")],
+                            ..PlanetData::default()
+                        })
+                        .collect(),
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            })
+            .collect();
+
+        ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_demographics__given_history__returns_series_with_missing_groups_as_zero() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec![
+            ("2200.01.01", vec![("Human", 10)], 0.5),
+            ("2200.02.01", vec![("Human", 11), ("Yaanari", 2)], 0.75),
+        ]
+        .into_iter()
+        .map(|(date, species, happiness)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from("NAME"),
+                demographics: Demographics {
+                    species: species
+                        .into_iter()
+                        .map(|(name, pops)| (String::from(name), pops))
+                        .collect(),
+                    happiness,
+                    ..Demographics::default()
+                },
+                ..EmpireData::default()
+            }],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_demographics_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/demographics")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: DemographicsSeriesDto = test::read_body_json(resp).await;
+        assert_eq!(
+            actual.dates,
+            vec![String::from("2200.01.01"), String::from("2200.02.01")]
+        );
+        assert_eq!(actual.species["Human"], vec![10, 11]);
+        assert_eq!(actual.species["Yaanari"], vec![0, 2]);
+        assert!(actual.jobs.is_empty());
+        assert_eq!(actual.happiness, vec![0.5, 0.75]);
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_military__given_history__returns_total_and_per_fleet_strength() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec![
+            ("2200.01.01", vec![(0, "First Fleet", 100f64)]),
+            (
+                "2200.02.01",
+                vec![(0, "Home Fleet", 150f64), (1, "Second Fleet", 50f64)],
+            ),
+            ("2200.03.01", vec![(1, "Second Fleet", 75f64)]),
+        ]
+        .into_iter()
+        .map(|(date, fleets)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from("NAME"),
+                fleets: fleets
+                    .into_iter()
+                    .map(|(id, name, military_power)| FleetData {
+                        id,
+                        name: String::from(name),
+                        military_power,
+                        ..FleetData::default()
+                    })
+                    .collect(),
+                ..EmpireData::default()
+            }],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_military_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/military")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: MilitarySeriesDto = test::read_body_json(resp).await;
+        assert_eq!(actual.total, vec![100f64, 200f64, 75f64]);
+        assert_eq!(
+            actual.fleets,
+            vec![
+                FleetSeriesDto {
+                    id: 0,
+                    name: String::from("Home Fleet"),
+                    military_power: vec![Some(100f64), Some(150f64), None],
+                },
+                FleetSeriesDto {
+                    id: 1,
+                    name: String::from("Second Fleet"),
+                    military_power: vec![None, Some(50f64), Some(75f64)],
+                },
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_leaders__given_history__returns_recruitments_level_ups_and_removals() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let leader = |id: i64, name: &str, level: i64| LeaderData {
+            id,
+            name: String::from(name),
+            class: String::from("scientist"),
+            level,
+            ..LeaderData::default()
+        };
+        let snapshots = vec![
+            (
+                "2200.01.01",
+                vec![leader(0, "Kaelin", 1), leader(1, "Orsa", 2)],
+            ),
+            (
+                "2200.02.01",
+                vec![leader(0, "Kaelin", 2), leader(1, "Orsa", 2)],
+            ),
+            (
+                "2200.03.01",
+                vec![leader(0, "Kaelin", 2), leader(2, "Vex", 1)],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, leaders)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from("NAME"),
+                leaders,
+                ..EmpireData::default()
+            }],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_leaders_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/leaders")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: LeaderEventsDto = test::read_body_json(resp).await;
+        let event =
+            |date: &str, kind: LeaderEventKind, id: i64, name: &str, level: i64| LeaderEventDto {
+                date: String::from(date),
+                kind,
+                id,
+                name: String::from(name),
+                class: String::from("scientist"),
+                level,
+            };
+        assert_eq!(
+            actual.events,
+            vec![
+                event("2200.02.01", LeaderEventKind::LevelUp, 0, "Kaelin", 2),
+                event("2200.03.01", LeaderEventKind::Recruited, 2, "Vex", 1),
+                event("2200.03.01", LeaderEventKind::Removed, 1, "Orsa", 2),
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_leaders__given_dismissed_leader__reports_removal() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let governor = LeaderData {
+            id: 1,
+            name: String::from("Orsa"),
+            class: String::from("governor"),
+            level: 3,
+            age: 41,
+            assignment: String::from("sector"),
+            ..LeaderData::default()
+        };
+        let snapshots = vec![("2200.01.01", vec![governor]), ("2200.02.01", vec![])]
+            .into_iter()
+            .map(|(date, leaders)| ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str(date).unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    leaders,
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            })
+            .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_leaders_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/leaders")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: LeaderEventsDto = test::read_body_json(resp).await;
+        assert_eq!(
+            actual.events,
+            vec![LeaderEventDto {
+                date: String::from("2200.02.01"),
+                kind: LeaderEventKind::Removed,
+                id: 1,
+                name: String::from("Orsa"),
+                class: String::from("governor"),
+                level: 3,
+            }]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_technologies__given_history__returns_date_each_tech_was_first_seen() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec![
+            ("2200.01.01", vec!["tech_lasers_1", "tech_corvettes"]),
+            (
+                "2200.02.01",
+                vec!["tech_lasers_1", "tech_corvettes", "tech_lasers_2"],
+            ),
+            (
+                "2200.03.01",
+                vec!["tech_lasers_1", "tech_corvettes", "tech_lasers_2"],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, researched)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from("NAME"),
+                tech: TechStatus {
+                    researched: researched
+                        .into_iter()
+                        .map(|technology| (String::from(technology), 1))
+                        .collect(),
+                    ..TechStatus::default()
+                },
+                ..EmpireData::default()
+            }],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_technologies_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/technologies")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: TechnologiesDto = test::read_body_json(resp).await;
+        assert_eq!(
+            actual.technologies,
+            vec![
+                TechnologyDto {
+                    technology: String::from("tech_corvettes"),
+                    first_seen: String::from("2200.01.01"),
+                },
+                TechnologyDto {
+                    technology: String::from("tech_lasers_1"),
+                    first_seen: String::from("2200.01.01"),
+                },
+                TechnologyDto {
+                    technology: String::from("tech_lasers_2"),
+                    first_seen: String::from("2200.02.01"),
+                },
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_identity__given_history__returns_adoption_dates_and_changes() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let policy = |policy: &str, selected: &str, date: Option<&str>| PolicyData {
+            policy: String::from(policy),
+            selected: String::from(selected),
+            date: date.map(|date| StellarisDate::from_str(date).unwrap()),
+        };
+        let snapshots = vec![
+            (
+                "2200.01.01",
+                "auth_democratic",
+                vec!["civic_beacon_of_liberty", "civic_idealistic_foundation"],
+                "diplo_stance_expansionist",
+            ),
+            (
+                "2200.02.01",
+                "auth_democratic",
+                vec!["civic_beacon_of_liberty", "civic_meritocracy"],
+                "diplo_stance_cooperative",
+            ),
+            (
+                "2200.03.01",
+                "auth_oligarchic",
+                vec!["civic_beacon_of_liberty", "civic_meritocracy"],
+                "diplo_stance_cooperative",
+            ),
+        ]
+        .into_iter()
+        .map(|(date, authority, civics, stance)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from("NAME"),
+                identity: EmpireIdentity {
+                    government: String::from("gov_representative_democracy"),
+                    authority: String::from(authority),
+                    civics: civics.into_iter().map(String::from).collect(),
+                    ethics: vec![String::from("ethic_xenophile")],
+                    origin: String::from("origin_default"),
+                    policies: vec![
+                        policy("diplomatic_stance", stance, None),
+                        policy(
+                            "robot_pop_policy",
+                            "robot_pops_outlawed",
+                            Some("2199.06.01"),
+                        ),
+                    ],
+                    ruler: None,
+                },
+                ..EmpireData::default()
+            }],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_identity_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/identity")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: IdentityDto = test::read_body_json(resp).await;
+        assert_eq!(actual.snapshots.len(), 3);
+        assert_eq!(
+            actual.snapshots[2].policies,
+            vec![
+                PolicyDto {
+                    policy: String::from("diplomatic_stance"),
+                    selected: String::from("diplo_stance_cooperative"),
+                    adopted: String::from("2200.02.01"),
+                },
+                PolicyDto {
+                    policy: String::from("robot_pop_policy"),
+                    selected: String::from("robot_pops_outlawed"),
+                    adopted: String::from("2199.06.01"),
+                },
+            ]
+        );
+        let change = |date: &str,
+                      kind: IdentityChangeKind,
+                      policy: Option<&str>,
+                      previous: Option<&str>,
+                      current: Option<&str>| IdentityChangeDto {
+            date: String::from(date),
+            kind,
+            policy: policy.map(String::from),
+            previous: previous.map(String::from),
+            current: current.map(String::from),
+        };
+        assert_eq!(
+            actual.changes,
+            vec![
+                change(
+                    "2200.02.01",
+                    IdentityChangeKind::Civic,
+                    None,
+                    Some("civic_idealistic_foundation"),
+                    None
+                ),
+                change(
+                    "2200.02.01",
+                    IdentityChangeKind::Civic,
+                    None,
+                    None,
+                    Some("civic_meritocracy")
+                ),
+                change(
+                    "2200.02.01",
+                    IdentityChangeKind::Policy,
+                    Some("diplomatic_stance"),
+                    Some("diplo_stance_expansionist"),
+                    Some("diplo_stance_cooperative")
+                ),
+                change(
+                    "2200.03.01",
+                    IdentityChangeKind::Authority,
+                    None,
+                    Some("auth_democratic"),
+                    Some("auth_oligarchic")
+                ),
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_trade__given_range__returns_collection_piracy_and_routes_per_date() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let system = |id: i64, owner: &str, trade: TradeHubData| SystemData {
+            id,
+            owner: Some(String::from(owner)),
+            trade,
+            ..SystemData::default()
+        };
+        let route = |id: i64, owner: &str| TradeRouteData {
+            id,
+            owner: String::from(owner),
+            from: 1,
+            to: 0,
+            delivered: 9.5,
+            path: vec![1, 0],
+        };
+        let snapshots = vec![("2200.01.01", 30.0), ("2200.02.01", 35.0)]
+            .into_iter()
+            .map(|(date, trade_value)| ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str(date).unwrap(),
+                empires: vec![EmpireData {
+                    name: String::from("NAME"),
+                    trade_value,
+                    ..EmpireData::default()
+                }],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![
+                    system(
+                        0,
+                        "NAME",
+                        TradeHubData {
+                            collected: 20.0,
+                            collected_from: vec![0, 1],
+                            throughput: 10.0,
+                            piracy: 2.0,
+                        },
+                    ),
+                    system(
+                        1,
+                        "NAME",
+                        TradeHubData {
+                            throughput: 5.0,
+                            piracy: 8.0,
+                            ..TradeHubData::default()
+                        },
+                    ),
+                    system(
+                        2,
+                        "OTHER",
+                        TradeHubData {
+                            collected: 7.0,
+                            collected_from: vec![2],
+                            throughput: 7.0,
+                            piracy: 3.0,
+                        },
+                    ),
+                ],
+                market: MarketData::default(),
+                trade_routes: vec![route(0, "NAME"), route(1, "OTHER")],
+                megastructures: vec![],
+            })
+            .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_trade_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/trade?from=2200.02.01")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: TradeDto = test::read_body_json(resp).await;
+        assert_eq!(actual.snapshots.len(), 1);
+        let snapshot = &actual.snapshots[0];
+        assert_eq!(snapshot.date, "2200.02.01");
+        assert_eq!(snapshot.trade_value, 35.0);
+        assert_eq!(snapshot.collected, 20.0);
+        assert_eq!(snapshot.piracy_losses, 7.0);
+        assert_eq!(
+            snapshot.hubs,
+            vec![TradeHubDto {
+                system: 0,
+                collected: 20.0,
+                collected_from: vec![0, 1],
+                piracy: 2.0,
+            }]
+        );
+        assert_eq!(
+            snapshot.routes,
+            vec![TradeRouteDto {
+                id: 0,
+                from: 1,
+                to: 0,
+                delivered: 9.5,
+                path: vec![1, 0],
+            }]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_empire_megastructures__given_history__returns_stage_completion_dates() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let megastructure = |id: i64, megastructure_type: &str, owner: &str| MegastructureData {
+            id,
+            megastructure_type: String::from(megastructure_type),
+            owner: Some(String::from(owner)),
+            system: id * 10,
+        };
+        let snapshots = vec![
+            (
+                "2200.01.01",
+                vec![
+                    megastructure(0, "dyson_sphere_1", "NAME"),
+                    megastructure(1, "ring_world_1", "OTHER"),
+                ],
+            ),
+            (
+                "2200.02.01",
+                vec![
+                    megastructure(0, "dyson_sphere_2", "NAME"),
+                    megastructure(1, "ring_world_2", "OTHER"),
+                    megastructure(2, "gateway_0", "NAME"),
+                ],
+            ),
+            (
+                "2200.03.01",
+                vec![
+                    megastructure(0, "dyson_sphere_2", "NAME"),
+                    megastructure(1, "ring_world_2", "OTHER"),
+                    megastructure(2, "gateway_final", "NAME"),
+                ],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, megastructures)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures,
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(empire_megastructures_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/empires/NAME/megastructures")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: MegastructuresDto = test::read_body_json(resp).await;
+        let stage = |stage: &str, completed: Option<&str>| MegastructureStageDto {
+            stage: String::from(stage),
+            completed: completed.map(String::from),
+        };
+        assert_eq!(
+            actual,
+            MegastructuresDto {
+                empire: String::from("NAME"),
+                megastructures: vec![
+                    MegastructureDto {
+                        id: 0,
+                        system: 0,
+                        megastructure_type: String::from("dyson_sphere_2"),
+                        stages: vec![
+                            stage("dyson_sphere_1", None),
+                            stage("dyson_sphere_2", Some("2200.02.01")),
+                        ],
+                    },
+                    MegastructureDto {
+                        id: 2,
+                        system: 20,
+                        megastructure_type: String::from("gateway_final"),
+                        stages: vec![
+                            stage("gateway_0", Some("2200.02.01")),
+                            stage("gateway_final", Some("2200.03.01")),
+                        ],
+                    },
+                ],
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_wars__given_history__returns_active_and_ended_wars_with_timelines() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let war = |id: i64, name: &str, attacker_exhaustion: f64| WarData {
+            id,
+            name: String::from(name),
+            start_date: StellarisDate::from_str("2200.01.01").unwrap(),
+            attackers: vec![String::from("NAME")],
+            defenders: vec![String::from("OTHER")],
+            attacker_war_goal: String::from("wg_conquest"),
+            defender_war_goal: None,
+            attacker_exhaustion,
+            defender_exhaustion: 0.0,
+        };
+        let truce = |id: i64, name: &str| TruceData {
+            id,
+            name: String::from(name),
+            start_date: StellarisDate::from_str("2200.02.01").unwrap(),
+            truce_type: Some(String::from("war")),
+            countries: vec![String::from("NAME"), String::from("OTHER")],
+        };
+        let snapshots = vec![
+            ("2200.01.01", vec![war(0, "First War", 0.1)], vec![]),
+            (
+                "2200.02.01",
+                vec![war(0, "First War", 0.2), war(1, "Second War", 0.0)],
+                vec![truce(0, "First Truce")],
+            ),
+            (
+                "2200.03.01",
+                vec![war(1, "Second War", 0.1)],
+                vec![truce(1, "Second Truce")],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, wars, truces)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![],
+            wars,
+            truces,
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_wars_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaigns/CAMPAIGN/wars")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: WarsDto = test::read_body_json(resp).await;
+        let expected_war = |id: i64, name: &str, end_date: Option<&str>, dates: Vec<&str>| WarDto {
+            id,
+            name: String::from(name),
+            start_date: String::from("2200.01.01"),
+            end_date: end_date.map(String::from),
+            active: end_date.is_none(),
+            attackers: vec![String::from("NAME")],
+            defenders: vec![String::from("OTHER")],
+            attacker_war_goal: String::from("wg_conquest"),
+            defender_war_goal: None,
+            dates: dates.into_iter().map(String::from).collect(),
+            attacker_exhaustion: vec![],
+            defender_exhaustion: vec![0.0, 0.0],
+        };
+        assert_eq!(
+            actual.wars,
+            vec![
+                WarDto {
+                    attacker_exhaustion: vec![0.1, 0.2],
+                    ..expected_war(
+                        0,
+                        "First War",
+                        Some("2200.03.01"),
+                        vec!["2200.01.01", "2200.02.01"]
+                    )
+                },
+                WarDto {
+                    attacker_exhaustion: vec![0.0, 0.1],
+                    ..expected_war(1, "Second War", None, vec!["2200.02.01", "2200.03.01"])
+                },
+            ]
+        );
+        let expected_truce = |id: i64, name: &str, end_date: Option<&str>| TruceDto {
+            id,
+            name: String::from(name),
+            start_date: String::from("2200.02.01"),
+            end_date: end_date.map(String::from),
+            active: end_date.is_none(),
+            truce_type: Some(String::from("war")),
+            countries: vec![String::from("NAME"), String::from("OTHER")],
+        };
+        assert_eq!(
+            actual.truces,
+            vec![
+                expected_truce(0, "First Truce", Some("2200.03.01")),
+                expected_truce(1, "Second Truce", None),
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_diplomacy__given_history__returns_federations_and_community_per_date() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec![
+            ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str("2200.01.01").unwrap(),
+                empires: vec![],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            },
+            ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str("2200.02.01").unwrap(),
+                empires: vec![],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![FederationData {
+                    id: 0,
+                    name: String::from("Sol Federation"),
+                    federation_type: String::from("research_federation"),
+                    level: 1,
+                    cohesion: 12.5,
+                    leader: Some(String::from("NAME")),
+                    members: vec![String::from("NAME"), String::from("OTHER")],
+                }],
+                galactic_community: GalacticCommunity {
+                    members: vec![String::from("NAME")],
+                    council: vec![],
+                    resolutions: vec![],
+                    voting: Some(String::from("resolution_mutualdefense_defense_pact")),
+                },
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            },
+        ];
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_diplomacy_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaigns/CAMPAIGN/diplomacy")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: DiplomacyDto = test::read_body_json(resp).await;
+        assert_eq!(
+            actual
+                .snapshots
+                .iter()
+                .map(|snapshot| snapshot.date.as_str())
+                .collect::<Vec<_>>(),
+            vec!["2200.01.01", "2200.02.01"]
+        );
+        assert!(actual.snapshots[0].federations.is_empty());
+        assert_eq!(
+            actual.snapshots[1].federations,
+            vec![FederationDto {
+                name: String::from("Sol Federation"),
+                federation_type: String::from("research_federation"),
+                level: 1,
+                cohesion: 12.5,
+                leader: Some(String::from("NAME")),
+                members: vec![String::from("NAME"), String::from("OTHER")],
+            }]
+        );
+        assert_eq!(
+            actual.snapshots[1].galactic_community,
+            GalacticCommunityDto {
+                members: vec![String::from("NAME")],
+                council: vec![],
+                resolutions: vec![],
+                voting: Some(String::from("resolution_mutualdefense_defense_pact")),
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_relations__given_history__returns_relation_matrix_per_date() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let relation = |owner: &str, country: &str, opinion: i64, rival: bool| RelationData {
+            owner: String::from(owner),
+            country: String::from(country),
+            opinion,
+            rival,
+            contact: true,
+            ..RelationData::default()
+        };
+        let snapshots = vec![
+            ("2200.01.01", vec![relation("NAME", "OTHER", 10, false)]),
+            (
+                "2200.02.01",
+                vec![
+                    relation("NAME", "OTHER", -50, true),
+                    relation("OTHER", "NAME", -40, true),
+                ],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, relations)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations,
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_relations_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaigns/CAMPAIGN/relations")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: RelationMatrixDto = test::read_body_json(resp).await;
+        let cell = |opinion: i64, rival: bool| {
+            Some(RelationDto {
+                opinion,
+                trust: 0.0,
+                treaties: vec![],
+                rival,
+                borders: false,
+            })
+        };
+        assert_eq!(
+            actual.countries,
+            vec![String::from("NAME"), String::from("OTHER")]
+        );
+        assert_eq!(
+            actual.snapshots[0].matrix,
+            vec![vec![None, cell(10, false)], vec![None, None]]
+        );
+        assert_eq!(
+            actual.snapshots[1].matrix,
+            vec![vec![None, cell(-50, true)], vec![cell(-40, true), None]]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_market__given_history__returns_prices_and_net_trade_value_per_empire() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let snapshots = vec![("2200.01.01", 1.0, 100.0), ("2200.02.01", 0.8, 250.0)]
+            .into_iter()
+            .map(|(date, price, sold)| ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str(date).unwrap(),
+                empires: vec![],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData {
+                    resources: vec![
+                        MarketResourceData {
+                            resource: ResourceClass::Minerals,
+                            fluctuation: (price - 1.0) * 100.0,
+                            price,
+                        },
+                        MarketResourceData {
+                            resource: ResourceClass::Alloys,
+                            fluctuation: 0.0,
+                            price: 4.0,
+                        },
+                    ],
+                    trades: vec![
+                        MarketTradeData {
+                            country: String::from("NAME"),
+                            resource: ResourceClass::Minerals,
+                            bought: 0.0,
+                            sold,
+                        },
+                        MarketTradeData {
+                            country: String::from("NAME"),
+                            resource: ResourceClass::Alloys,
+                            bought: 10.0,
+                            sold: 0.0,
+                        },
+                    ],
+                },
+                trade_routes: vec![],
+                megastructures: vec![],
+            })
+            .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_market_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaigns/CAMPAIGN/market")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: MarketDto = test::read_body_json(resp).await;
+        assert_eq!(actual.dates, vec!["2200.01.01", "2200.02.01"]);
+        assert_eq!(
+            actual.prices[&ResourceClass::Minerals],
+            vec![Some(1.0), Some(0.8)]
+        );
+        assert_eq!(
+            actual.prices[&ResourceClass::Alloys],
+            vec![Some(4.0), Some(4.0)]
+        );
+        assert_eq!(
+            actual.empires,
+            vec![TradeVolumeSeriesDto {
+                empire: String::from("NAME"),
+                bought: vec![40.0, 40.0],
+                sold: vec![100.0, 200.0],
+                net: vec![60.0, 160.0],
+            }]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_galaxy__given_date__returns_systems_and_deduplicated_hyperlanes_at_date() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let system = |id: i64, hyperlanes: Vec<i64>, owner: Option<&str>| SystemData {
+            id,
+            name: format!("System {}", id),
+            hyperlanes,
+            owner: owner.map(String::from),
+            ..SystemData::default()
+        };
+        let snapshots = vec![
+            ("2200.01.01", None),
+            ("2200.02.01", Some("NAME")),
+            ("2200.03.01", Some("OTHER")),
+        ]
+        .into_iter()
+        .map(|(date, owner)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![],
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![system(0, vec![1], owner), system(1, vec![0], None)],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(galaxy_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/galaxy?date=2200.02.15")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: GalaxyDto = test::read_body_json(resp).await;
+        assert_eq!(actual.date, "2200.02.01");
+        assert_eq!(
+            actual.systems[0],
+            SystemDto {
+                id: 0,
+                name: String::from("System 0"),
+                x: 0.0,
+                y: 0.0,
+                star_class: String::new(),
+                owner: Some(String::from("NAME")),
+            }
+        );
+        assert_eq!(actual.hyperlanes, vec![HyperlaneDto { from: 0, to: 1 }]);
+    }
+
+    #[actix_rt::test]
+    async fn test_galaxy__given_date_before_first_snapshot__returns_not_found() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(vec![ModelDataPoint {
+                campaign_name: String::from("CAMPAIGN"),
+                date: StellarisDate::from_str("2200.02.01").unwrap(),
+                empires: vec![],
+                wars: vec![],
+                truces: vec![],
+                federations: vec![],
+                galactic_community: GalacticCommunity::default(),
+                relations: vec![],
+                systems: vec![],
+                market: MarketData::default(),
+                trade_routes: vec![],
+                megastructures: vec![],
+            }]),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(galaxy_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/galaxy?date=2200.01.01")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert_eq!(resp.status(), 404);
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_leaderboard__given_history__returns_empires_ranked_by_score_per_date() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let empire = |name: &str, victory_rank: i64, victory_score: f64| EmpireData {
+            name: String::from(name),
+            victory_rank,
+            victory_score,
+            military_power: victory_score * 2.0,
+            fleet_size: 3,
+            ..EmpireData::default()
+        };
+        let snapshots = vec![
+            (
+                "2200.01.01",
+                vec![empire("NAME", 1, 100.0), empire("OTHER", 2, 50.0)],
+            ),
+            (
+                "2200.02.01",
+                vec![empire("NAME", 2, 120.0), empire("OTHER", 1, 150.0)],
+            ),
+        ]
+        .into_iter()
+        .map(|(date, empires)| ModelDataPoint {
+            campaign_name: String::from("CAMPAIGN"),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires,
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        })
+        .collect();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(snapshots),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_leaderboard_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaign/leaderboard")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        let actual: LeaderboardDto = test::read_body_json(resp).await;
+        assert_eq!(actual.campaign, "CAMPAIGN");
+        assert_eq!(actual.snapshots.len(), 2);
+        assert_eq!(actual.snapshots[0].date, "2200.01.01");
+        let names = |index: usize| -> Vec<String> {
+            actual.snapshots[index]
+                .empires
+                .iter()
+                .map(|entry| entry.name.clone())
+                .collect()
+        };
+        assert_eq!(names(0), vec!["NAME", "OTHER"]);
+        assert_eq!(names(1), vec!["OTHER", "NAME"]);
+        let leader = &actual.snapshots[1].empires[0];
+        assert_eq!(leader.victory_rank, 1);
+        assert_eq!(leader.victory_score, 150.0);
+        assert_eq!(leader.military_power, 300.0);
+        assert_eq!(leader.fleet_size, 3);
+    }
+
+    #[actix_rt::test]
+    async fn test_campaign_leaderboard__given_no_history__returns_not_found() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let custodian = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_snapshots(vec![]),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Data::new(custodian))
+                .service(campaign_leaderboard_test),
+        )
+        .await;
+        let req = test::TestRequest::with_header("content-type", "application/json")
+            .uri("/campaign/leaderboard")
+            .to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert_eq!(resp.status(), 404);
+    }
+
+    #[actix_rt::test]
+    async fn test_updates__given_client__returns_event_stream() {
+        let (_sender, receiver) = channel();
+        let mut app = test::init_service(
+            App::new()
+                .app_data(Broadcaster::create(receiver))
+                .service(updates),
+        )
+        .await;
+        let req = test::TestRequest::get().uri("/updates").to_request();
+
+        let resp = test::call_service(&mut app, req).await;
+
+        assert!(resp.status().is_success());
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+    }
 }
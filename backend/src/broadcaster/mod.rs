@@ -1 +1,129 @@
+use std::{
+    sync::{mpsc::Receiver, Mutex},
+    thread,
+    time::Duration,
+};
 
+use actix_web::web::{Bytes, Data};
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use stellarust::dto::CampaignUpdateDto;
+
+const PING_INTERVAL: Duration = Duration::from_secs(10);
+// SSE comment line, ignored by EventSource but enough to notice a closed connection.
+const PING: &[u8] = b": ping\n\n";
+
+pub struct Broadcaster {
+    clients: Vec<UnboundedSender<Bytes>>,
+}
+
+impl Broadcaster {
+    pub fn create(receiver: Receiver<CampaignUpdateDto>) -> Data<Mutex<Self>> {
+        let me = Data::new(Mutex::new(Broadcaster { clients: vec![] }));
+        Broadcaster::start(me.clone(), receiver);
+        Broadcaster::start_pings(me.clone());
+        me
+    }
+
+    fn start(me: Data<Mutex<Self>>, receiver: Receiver<CampaignUpdateDto>) {
+        thread::spawn(move || {
+            for update in receiver.iter() {
+                log::info!("Broadcasting {} {}", update.campaign, update.date);
+                me.lock().unwrap().send(&update);
+            }
+        });
+    }
+
+    fn start_pings(me: Data<Mutex<Self>>) {
+        thread::spawn(move || loop {
+            thread::sleep(PING_INTERVAL);
+            me.lock().unwrap().ping();
+        });
+    }
+
+    pub fn new_client(&mut self) -> UnboundedReceiver<Bytes> {
+        let (sender, receiver) = unbounded();
+        self.clients.push(sender);
+        receiver
+    }
+
+    pub fn send(&mut self, update: &CampaignUpdateDto) {
+        let message = match serde_json::to_string(update) {
+            Ok(json) => Bytes::from(format!("data: {}\n\n", json)),
+            Err(e) => {
+                log::error!("Could not serialize update: {}", e);
+                return;
+            }
+        };
+        self.broadcast(message);
+    }
+
+    pub fn ping(&mut self) {
+        self.broadcast(Bytes::from_static(PING));
+    }
+
+    fn broadcast(&mut self, message: Bytes) {
+        self.clients
+            .retain(|client| client.unbounded_send(message.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use futures::StreamExt;
+    use stellarust::dto::CampaignUpdateDto;
+
+    use super::Broadcaster;
+
+    #[actix_rt::test]
+    async fn create__given_update__sends_event_to_each_client() {
+        let (sender, receiver) = channel();
+        let broadcaster = Broadcaster::create(receiver);
+        let mut first = broadcaster.lock().unwrap().new_client();
+        let mut second = broadcaster.lock().unwrap().new_client();
+
+        sender
+            .send(CampaignUpdateDto {
+                campaign: String::from("CAMPAIGN"),
+                date: String::from("2200.01.01"),
+                empires: vec![String::from("NAME")],
+            })
+            .unwrap();
+
+        let expected =
+            "data: {\"campaign\":\"CAMPAIGN\",\"date\":\"2200.01.01\",\"empires\":[\"NAME\"]}\n\n";
+        assert_eq!(first.next().await.unwrap(), expected);
+        assert_eq!(second.next().await.unwrap(), expected);
+    }
+
+    #[actix_rt::test]
+    async fn send__given_disconnected_client__drops_client() {
+        let (_sender, receiver) = channel();
+        let broadcaster = Broadcaster::create(receiver);
+        let client = broadcaster.lock().unwrap().new_client();
+        drop(client);
+
+        broadcaster.lock().unwrap().send(&CampaignUpdateDto {
+            campaign: String::from("CAMPAIGN"),
+            date: String::from("2200.01.01"),
+            empires: vec![],
+        });
+
+        assert!(broadcaster.lock().unwrap().clients.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn ping__given_clients__pings_live_clients_and_drops_disconnected() {
+        let (_sender, receiver) = channel();
+        let broadcaster = Broadcaster::create(receiver);
+        let mut live = broadcaster.lock().unwrap().new_client();
+        let disconnected = broadcaster.lock().unwrap().new_client();
+        drop(disconnected);
+
+        broadcaster.lock().unwrap().ping();
+
+        assert_eq!(live.next().await.unwrap(), ": ping\n\n");
+        assert_eq!(broadcaster.lock().unwrap().clients.len(), 1);
+    }
+}
@@ -0,0 +1,124 @@
+use clausewitz_parser::Val;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+pub type ImportResult<T> = std::result::Result<T, DataImportError>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ValKind {
+    Dict,
+    Array,
+    Set,
+    StringLiteral,
+    Date,
+    Decimal,
+    Integer,
+    Number,
+    Other,
+}
+
+impl From<&Val<'_>> for ValKind {
+    fn from(val: &Val<'_>) -> Self {
+        match val {
+            Val::Dict(_) => ValKind::Dict,
+            Val::Array(_) => ValKind::Array,
+            Val::Set(_) => ValKind::Set,
+            Val::StringLiteral(_) => ValKind::StringLiteral,
+            Val::Date(_) => ValKind::Date,
+            Val::Decimal(_) => ValKind::Decimal,
+            Val::Integer(_) => ValKind::Integer,
+            _ => ValKind::Other,
+        }
+    }
+}
+
+impl Display for ValKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DataImportError {
+    Parse {
+        file: String,
+        message: String,
+    },
+    MissingKey {
+        path: String,
+    },
+    IndexOutOfBounds {
+        path: String,
+        index: usize,
+        len: usize,
+    },
+    UnexpectedKind {
+        path: String,
+        expected: ValKind,
+        actual: ValKind,
+    },
+    InvalidValue {
+        path: String,
+        message: String,
+    },
+}
+
+impl DataImportError {
+    pub fn unexpected_kind(expected: ValKind, actual: &Val<'_>) -> Self {
+        DataImportError::UnexpectedKind {
+            path: String::new(),
+            expected,
+            actual: ValKind::from(actual),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        match self {
+            DataImportError::Parse { file, .. } => file,
+            DataImportError::MissingKey { path }
+            | DataImportError::IndexOutOfBounds { path, .. }
+            | DataImportError::UnexpectedKind { path, .. }
+            | DataImportError::InvalidValue { path, .. } => path,
+        }
+    }
+
+    pub fn within(mut self, prefix: &str) -> Self {
+        let path = match &mut self {
+            DataImportError::Parse { .. } => return self,
+            DataImportError::MissingKey { path }
+            | DataImportError::IndexOutOfBounds { path, .. }
+            | DataImportError::UnexpectedKind { path, .. }
+            | DataImportError::InvalidValue { path, .. } => path,
+        };
+        *path = match (prefix.is_empty(), path.is_empty()) {
+            (true, _) => path.clone(),
+            (false, true) => String::from(prefix),
+            (false, false) => format!("{}/{}", prefix, path),
+        };
+        self
+    }
+}
+
+impl Error for DataImportError {}
+
+impl Display for DataImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DataImportError::Parse { file, message } => {
+                write!(f, "Error importing {}:\n{}", file, message)
+            }
+            DataImportError::MissingKey { path } => write!(f, "{}: key not found", path),
+            DataImportError::IndexOutOfBounds { path, index, len } => {
+                write!(f, "{}: index {} out of bounds({})", path, index, len)
+            }
+            DataImportError::UnexpectedKind {
+                path,
+                expected,
+                actual,
+            } => write!(f, "{}: expected {} but found {}", path, expected, actual),
+            DataImportError::InvalidValue { path, message } => write!(f, "{}: {}", path, message),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use data_model::StellarisDate;
+
+use super::{error::ImportResult, query::Node};
+
+pub trait FromVal: Sized {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self>;
+}
+
+impl FromVal for f64 {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        node.as_f64()
+    }
+}
+
+impl FromVal for i64 {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        node.as_i64()
+    }
+}
+
+impl FromVal for String {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        Ok(String::from(node.as_str()?))
+    }
+}
+
+impl FromVal for StellarisDate {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        node.as_date()
+    }
+}
+
+impl<T: FromVal> FromVal for Vec<T> {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        node.as_vec()?.iter().map(T::from_val).collect()
+    }
+}
+
+impl<T: FromVal> FromVal for Option<T> {
+    fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+        Ok(Some(T::from_val(node)?))
+    }
+}
+
+macro_rules! from_val {
+    ($type:ident { $($field:ident $(: $query:literal $(= $default:expr)?)?),* $(,)? }) => {
+        impl FromVal for $type {
+            fn from_val(node: &Node<'_>) -> ImportResult<Self> {
+                Ok($type {
+                    $($field: from_val!(@field node $(, $query $(, $default)?)?),)*
+                })
+            }
+        }
+    };
+    (@field $node:ident) => {
+        Default::default()
+    };
+    (@field $node:ident, $query:literal) => {
+        $node.decode($query)?
+    };
+    (@field $node:ident, $query:literal, $default:expr) => {
+        $node.decode_or($query, $default)?
+    };
+}
+
+pub(crate) use from_val;
+
+#[cfg(test)]
+mod tests {
+    use clausewitz_parser::root;
+
+    use super::*;
+    use crate::data_import::{DataImportError, ValKind};
+
+    #[derive(Debug, PartialEq)]
+    struct Leader {
+        name: String,
+        level: i64,
+        age: f64,
+        traits: Vec<String>,
+        date_added: Option<StellarisDate>,
+    }
+
+    from_val! {
+        Leader {
+            name: "name/full_names/key",
+            level: "level",
+            age: "age" = 30.0,
+            traits: "traits" = vec![],
+            date_added: "date_added" = None,
+        }
+    }
+
+    #[test]
+    fn from_val__given_mapping__decodes_renamed_fields_and_defaults() {
+        let (_, leader) = root(
+            r###"
+            name={ full_names={ key="Kaelin" } }
+            level=3
+            traits={ "leader_trait_resilient" "leader_trait_stubborn" }
+            date_added="2200.03.01"
+            "###,
+        )
+        .unwrap();
+
+        let leader = Leader::from_val(&Node::root(&leader)).unwrap();
+
+        assert_eq!(
+            leader,
+            Leader {
+                name: String::from("Kaelin"),
+                level: 3,
+                age: 30.0,
+                traits: vec![
+                    String::from("leader_trait_resilient"),
+                    String::from("leader_trait_stubborn")
+                ],
+                date_added: Some(StellarisDate::new(2200, 3, 1).unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn from_val__given_missing_required_field__returns_error_with_path() {
+        let (_, leader) = root("name={ full_names={ key=\"Kaelin\" } }").unwrap();
+
+        let error = Leader::from_val(&Node::root(&leader)).unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::MissingKey {
+                path: String::from("level")
+            }
+        );
+    }
+
+    #[test]
+    fn from_val__given_mismatched_field__returns_error_with_path() {
+        let (_, leaders) = root(
+            r###"
+            leader={
+                name={ full_names={ key="Kaelin" } }
+                level=3
+                traits={ 1 2 }
+            }
+            "###,
+        )
+        .unwrap();
+
+        let error = Leader::from_val(&Node::root(&leaders).get("leader").unwrap()).unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::UnexpectedKind {
+                path: String::from("leader/traits/0"),
+                expected: ValKind::StringLiteral,
+                actual: ValKind::Integer,
+            }
+        );
+    }
+}
@@ -1,17 +1,56 @@
 use crate::unzipper::Unzipper;
 use anyhow::Result;
 use clausewitz_parser::{root, Val};
-use data_model::{Budget, EmpireData, ModelDataPoint, Resources};
-use std::{
-    collections::HashMap,
-    error::Error,
-    fmt::{self, Debug, Display, Formatter},
-    path::{Path, PathBuf},
+use data_model::{
+    Budget, Demographics, EmpireData, EmpireIdentity, FederationData, FleetData, GalacticCommunity,
+    LeaderData, MarketData, MarketResourceData, MarketTradeData, MegastructureData, ModelDataPoint,
+    PlanetData, PolicyData, RelationData, ResearchData, Resources, StellarisDate, SystemData,
+    TechStatus, TradeHubData, TradeRouteData, TruceData, WarData,
 };
-use stellarust::dto::{BudgetComponent, ResourceClass};
+use std::{collections::HashMap, convert::TryFrom, num::ParseIntError, path::Path};
+use stellarust::dto::ResourceClass;
 use strum::IntoEnumIterator;
 
-use super::Key;
+use super::{
+    error::{DataImportError, ImportResult, ValKind},
+    from_val::{from_val, FromVal},
+    query::Node,
+    Key,
+};
+
+// Placeholder id the save uses for references that point at nothing.
+const NO_ID: i64 = 4294967295;
+
+const TREATIES: [&str; 8] = [
+    "alliance",
+    "defensive_pact",
+    "non_aggression_pledge",
+    "commercial_pact",
+    "research_agreement",
+    "migration_access",
+    "embassy",
+    "guarantee",
+];
+
+const MARKET_RESOURCES: [(&str, Option<f64>); 17] = [
+    ("time", None),
+    ("energy", None),
+    ("minerals", Some(1.0)),
+    ("food", Some(1.0)),
+    ("physics_research", None),
+    ("society_research", None),
+    ("engineering_research", None),
+    ("influence", None),
+    ("unity", None),
+    ("consumer_goods", Some(2.0)),
+    ("alloys", Some(4.0)),
+    ("volatile_motes", Some(10.0)),
+    ("exotic_gases", Some(10.0)),
+    ("rare_crystals", Some(10.0)),
+    ("sr_living_metal", Some(20.0)),
+    ("sr_zro", Some(20.0)),
+    ("sr_dark_matter", Some(20.0)),
+];
 
 pub struct DataImport {}
 
@@ -21,15 +60,24 @@ pub struct DataImportResult<'a> {
 }
 
 #[derive(Debug, PartialEq)]
-pub struct DataImportError {
-    err: String,
+pub struct PartialImport {
+    pub data_point: ModelDataPoint,
+    pub skipped: Vec<SkippedEntry>,
 }
 
-impl Error for DataImportError {}
+/// A part of the save left out of the import, e.g. `war/3` or `market`, with the error it caused.
+#[derive(Debug, PartialEq)]
+pub struct SkippedEntry {
+    pub entry: String,
+    pub error: DataImportError,
+}
 
-impl Display for DataImportError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Debug::fmt(&self.err, f)
+impl SkippedEntry {
+    fn new(entry: &str, error: DataImportError) -> Self {
+        SkippedEntry {
+            entry: String::from(entry),
+            error,
+        }
     }
 }
 
@@ -46,302 +94,966 @@ impl DataImport {
 
         let result = DataImportResult { meta, gamestate };
 
-        Ok(ModelDataPoint::from(result))
+        let import = PartialImport::try_from(result)?;
+        for skipped in import.skipped.iter() {
+            log::warn!(
+                "Skipped {} in {:?}: {}",
+                skipped.entry,
+                path.file_name().unwrap_or_default(),
+                skipped.error
+            );
+        }
+        Ok(import.data_point)
     }
-    fn from_meta<'a>(string: &'a str) -> Result<Val<'a>> {
+    fn from_meta<'a>(string: &'a str) -> ImportResult<Val<'a>> {
         let result = root(string);
         match result {
             Ok((_, val)) => Ok(val),
-            Err(e) => Err(anyhow::Error::from(DataImportError {
-                err: format!("Error importing meta:\n{}", e),
-            })),
+            Err(e) => Err(DataImportError::Parse {
+                file: String::from("meta"),
+                message: e.to_string(),
+            }),
         }
     }
-    fn from_gamestate<'a>(string: &'a str) -> Result<Val<'a>> {
+    fn from_gamestate<'a>(string: &'a str) -> ImportResult<Val<'a>> {
         let result = root(string);
         match result {
             Ok((_, val)) => Ok(val),
-            Err(e) => Err(anyhow::Error::from(DataImportError {
-                err: format!("Error importing gamestate:\n{}", e),
-            })),
+            Err(e) => Err(DataImportError::Parse {
+                file: String::from("gamestate"),
+                message: e.to_string(),
+            }),
         }
     }
 }
 
-impl From<DataImportResult<'_>> for ModelDataPoint {
-    fn from(result: DataImportResult<'_>) -> Self {
+impl TryFrom<DataImportResult<'_>> for PartialImport {
+    type Error = DataImportError;
+
+    fn try_from(result: DataImportResult<'_>) -> ImportResult<Self> {
         data_point_from_parse_result(&result)
     }
 }
 
-fn data_point_from_parse_result(result: &DataImportResult<'_>) -> ModelDataPoint {
-    let meta = &result.meta;
-    let gamestate = &result.gamestate;
+fn data_point_from_parse_result(result: &DataImportResult<'_>) -> ImportResult<PartialImport> {
+    let meta = Node::root(&result.meta);
+    let gamestate = Node::root(&result.gamestate);
+
+    let _required_dlcs = get_required_dlcs_from_meta(&meta)?;
+    let campaign_name = get_name_from_meta(&meta)?;
+    let date = get_date_from_meta(&meta)?;
+
+    let (empires, mut skipped) = get_empires_from_gamestate(&gamestate)?;
+    let (wars, skipped_wars) = get_wars_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_wars);
+    let (truces, skipped_truces) = get_truces_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_truces);
+    let (federations, skipped_federations) = get_federations_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_federations);
+    let (relations, skipped_relations) = get_relations_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_relations);
+    let (systems, skipped_systems) = get_systems_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_systems);
+    let (trade_routes, skipped_trade_routes) = get_trade_routes_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_trade_routes);
+    let (megastructures, skipped_megastructures) = get_megastructures_from_gamestate(&gamestate)?;
+    skipped.extend(skipped_megastructures);
+    let galactic_community = match get_galactic_community(&gamestate) {
+        Ok(galactic_community) => galactic_community,
+        Err(e) => {
+            skipped.push(SkippedEntry::new("galactic_community", e));
+            GalacticCommunity::default()
+        }
+    };
+    let market = match get_market(&gamestate) {
+        Ok(market) => market,
+        Err(e) => {
+            skipped.push(SkippedEntry::new("market", e));
+            MarketData::default()
+        }
+    };
+
+    Ok(PartialImport {
+        data_point: ModelDataPoint {
+            campaign_name,
+            date,
+            empires,
+            wars,
+            truces,
+            federations,
+            galactic_community,
+            relations,
+            systems,
+            market,
+            trade_routes,
+            megastructures,
+        },
+        skipped,
+    })
+}
+
+fn get_name_from_meta(meta: &Node<'_>) -> ImportResult<String> {
+    Ok(String::from(meta.get("name")?.as_str()?))
+}
+
+fn get_date_from_meta(meta: &Node<'_>) -> ImportResult<StellarisDate> {
+    meta.get("date")?.as_date()
+}
 
-    let _required_dlcs = get_required_dlcs_from_meta(meta);
-    let campaign_name = get_name_from_meta(meta);
+fn get_required_dlcs_from_meta(meta: &Node<'_>) -> ImportResult<Vec<String>> {
+    Ok(meta
+        .get("required_dlcs")?
+        .as_vec()?
+        .iter()
+        .filter_map(|dlc| dlc.as_str().ok())
+        .map(String::from)
+        .collect())
+}
 
-    let empires = get_empires_from_gamestate(gamestate).unwrap();
+fn get_empires_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<EmpireData>, Vec<SkippedEntry>)> {
+    let mut empires = vec![];
+    let mut skipped = vec![];
+    for country in gamestate.get("country")?.select("*")? {
+        if country.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_empire_data(&country, gamestate, &mut skipped) {
+            Ok(Some(empire)) => empires.push(empire),
+            Ok(None) => {}
+            Err(e) => skipped.push(SkippedEntry::new(country.path(), e)),
+        }
+    }
+    Ok((empires, skipped))
+}
 
-    ModelDataPoint {
-        campaign_name,
-        empires,
+fn get_wars_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<WarData>, Vec<SkippedEntry>)> {
+    let mut wars = vec![];
+    let mut skipped = vec![];
+    for war in gamestate.select("war/*")? {
+        if war.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_war_data(&war, gamestate) {
+            Ok(war) => wars.push(war),
+            Err(e) => skipped.push(SkippedEntry::new(war.path(), e)),
+        }
     }
+    Ok((wars, skipped))
 }
 
-fn get_name_from_meta(meta: &Val<'_>) -> String {
-    String::from(get_string_contents(
-        get_val_from_path(PathBuf::from("name"), meta).unwrap(),
-    ))
+fn get_war_data(war: &Node<'_>, gamestate: &Node<'_>) -> ImportResult<WarData> {
+    let mut war_data = WarData::from_val(war)?;
+    war_data.id = get_id(war)?;
+    war_data.name = get_localised_name(war)?;
+    war_data.attackers = get_country_names(gamestate, &war.select("attackers/*/country")?)?;
+    war_data.defenders = get_country_names(gamestate, &war.select("defenders/*/country")?)?;
+    Ok(war_data)
 }
 
-fn get_required_dlcs_from_meta(meta: &Val<'_>) -> Vec<String> {
-    let required_dlcs = if let Val::Dict(pairs) = meta {
-        pairs
-            .into_iter()
-            .filter_map(|(k, v)| if *k == "required_dlcs" { Some(v) } else { None })
-            .next()
-            .unwrap()
-    } else {
-        unimplemented!("This should be a dict");
-    };
-    if let Val::Set(dlc_list) = required_dlcs {
-        return dlc_list
-            .into_iter()
-            .filter_map(|val| {
-                if let Val::StringLiteral(string_litteral) = val {
-                    Some(String::from(*string_litteral))
-                } else {
-                    None
+fn get_truces_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<TruceData>, Vec<SkippedEntry>)> {
+    let mut skipped = vec![];
+    // The truce section doesn't list its parties, each country's relation points at the truce.
+    let mut parties: HashMap<i64, Vec<String>> = HashMap::new();
+    for country in gamestate.get("country")?.select("*")? {
+        if country.kind() != ValKind::Dict {
+            continue;
+        }
+        for truce in country.select("relations_manager/relation[]/truce")? {
+            match (truce.as_i64(), country.decode::<String>("name")) {
+                (Ok(truce), Ok(name)) => {
+                    let countries = parties.entry(truce).or_default();
+                    if !countries.contains(&name) {
+                        countries.push(name);
+                    }
                 }
-            })
-            .collect();
-    } else {
-        unimplemented!("This should be a set")
+                (Err(e), _) | (_, Err(e)) => skipped.push(SkippedEntry::new(truce.path(), e)),
+            }
+        }
+    }
+
+    let mut truces = vec![];
+    for truce in gamestate.select("truce/*")? {
+        if truce.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_truce_data(&truce) {
+            Ok(mut truce) => {
+                truce.countries = parties.remove(&truce.id).unwrap_or_default();
+                truces.push(truce);
+            }
+            Err(e) => skipped.push(SkippedEntry::new(truce.path(), e)),
+        }
     }
+    Ok((truces, skipped))
+}
+
+fn get_truce_data(truce: &Node<'_>) -> ImportResult<TruceData> {
+    let mut truce_data = TruceData::from_val(truce)?;
+    truce_data.id = get_id(truce)?;
+    truce_data.name = get_localised_name(truce)?;
+    Ok(truce_data)
 }
 
-fn get_empires_from_gamestate(gamestate: &Val<'_>) -> Result<Vec<EmpireData>> {
-    let country_list = get_array_contents(get_val_from_path(PathBuf::from("country"), gamestate)?);
+fn get_federations_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<FederationData>, Vec<SkippedEntry>)> {
+    let mut federations = vec![];
+    let mut skipped = vec![];
+    for federation in gamestate.select("federation/*")? {
+        if federation.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_federation_data(&federation, gamestate) {
+            Ok(federation) => federations.push(federation),
+            Err(e) => skipped.push(SkippedEntry::new(federation.path(), e)),
+        }
+    }
+    Ok((federations, skipped))
+}
 
-    Ok(country_list
+fn get_federation_data(
+    federation: &Node<'_>,
+    gamestate: &Node<'_>,
+) -> ImportResult<FederationData> {
+    let mut federation_data = FederationData::from_val(federation)?;
+    federation_data.id = get_id(federation)?;
+    federation_data.name = get_localised_name(federation)?;
+    federation_data.leader = get_country_names(gamestate, &federation.select("leader")?)?
         .into_iter()
-        .filter_map(|val| get_empire_data(val).ok())
-        .collect())
+        .next();
+    federation_data.members = get_country_names(gamestate, &federation.select("members/*")?)?;
+    Ok(federation_data)
 }
 
-#[derive(Debug, PartialEq)]
-pub struct PathParseError {
-    err: String,
+fn get_galactic_community(gamestate: &Node<'_>) -> ImportResult<GalacticCommunity> {
+    let galactic_community = match gamestate.get("galactic_community") {
+        Ok(galactic_community) => galactic_community,
+        Err(DataImportError::MissingKey { .. }) => return Ok(GalacticCommunity::default()),
+        Err(e) => return Err(e),
+    };
+
+    let mut galactic_community_data = GalacticCommunity::from_val(&galactic_community)?;
+    galactic_community_data.members =
+        get_country_names(gamestate, &galactic_community.select("members/*")?)?;
+    galactic_community_data.council =
+        get_country_names(gamestate, &galactic_community.select("council/*")?)?;
+    Ok(galactic_community_data)
 }
 
-impl Error for PathParseError {}
+fn get_market(gamestate: &Node<'_>) -> ImportResult<MarketData> {
+    let market = match gamestate.get("market") {
+        Ok(market) => market,
+        Err(DataImportError::MissingKey { .. }) => return Ok(MarketData::default()),
+        Err(e) => return Err(e),
+    };
+    let fluctuations = market.decode_or::<Vec<f64>>("fluctuations", vec![])?;
+    if fluctuations.is_empty() {
+        return Ok(MarketData::default());
+    }
 
-impl Display for PathParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Debug::fmt(&self.err, f)
+    let resources: Vec<(usize, ResourceClass, f64)> = MARKET_RESOURCES
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (key, base_price))| {
+            let class = ResourceClass::iter().find(|class| class.key() == *key)?;
+            Some((index, class, (*base_price)?))
+        })
+        .collect();
+
+    let mut market_data = MarketData::default();
+    for (index, class, base_price) in resources.iter() {
+        if let Some(fluctuation) = fluctuations.get(*index) {
+            market_data.resources.push(MarketResourceData {
+                resource: class.clone(),
+                fluctuation: *fluctuation,
+                price: base_price * (1.0 + fluctuation / 100.0),
+            });
+        }
     }
-}
 
-fn get_val_from_path<'a>(pathbuf: PathBuf, root: &'a Val<'a>) -> Result<&'a Val<'a>> {
-    let mut curr_val = root;
-    for path_component in pathbuf.into_iter() {
-        if let Ok(array_index) = path_component.to_str().unwrap().parse::<usize>() {
-            if let Val::Array(array) = curr_val {
-                if array_index < array.len() {
-                    curr_val = array.get(array_index).unwrap();
-                } else {
-                    return Err(anyhow::Error::from(PathParseError {
-                        err: format!("Index {} out of bounds({})", array_index, array.len()),
-                    }));
-                }
-            } else {
-                return Err(anyhow::Error::from(PathParseError {
-                    err: format!("Expected an array"),
-                }));
+    let bought = market.decode_or::<Vec<Vec<f64>>>("resources_bought/amount", vec![])?;
+    let sold = market.decode_or::<Vec<Vec<f64>>>("resources_sold/amount", vec![])?;
+    let countries = market.select("id/*")?;
+    for (position, country) in get_country_names(gamestate, &countries)?
+        .into_iter()
+        .enumerate()
+    {
+        let amount = |amounts: &[Vec<f64>], index: usize| {
+            amounts
+                .get(position)
+                .and_then(|amounts| amounts.get(index))
+                .copied()
+                .unwrap_or(0.0)
+        };
+        for (index, class, _) in resources.iter() {
+            let (bought, sold) = (amount(&bought, *index), amount(&sold, *index));
+            if bought > 0.0 || sold > 0.0 {
+                market_data.trades.push(MarketTradeData {
+                    country: country.clone(),
+                    resource: class.clone(),
+                    bought,
+                    sold,
+                });
             }
-        } else {
-            if let Val::Dict(dict) = curr_val {
-                let mut found = false;
-                for (k, v) in dict.into_iter() {
-                    if k == &path_component.to_str().unwrap() {
-                        curr_val = v;
-                        found = true;
-                        break;
-                    }
-                }
-                if !found {
-                    return Err(anyhow::Error::from(PathParseError {
-                        err: format!("Key {} not found in dict", path_component.to_str().unwrap()),
-                    }));
-                }
-            } else {
-                return Err(anyhow::Error::from(PathParseError {
-                    err: format!("Expected a dict"),
-                }));
+        }
+    }
+    Ok(market_data)
+}
+
+fn get_relations_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<RelationData>, Vec<SkippedEntry>)> {
+    let mut relations = vec![];
+    let mut skipped = vec![];
+    for country in gamestate.get("country")?.select("*")? {
+        if country.kind() != ValKind::Dict {
+            continue;
+        }
+        for relation in country.select("relations_manager/relation[]")? {
+            match get_relation_data(&country, &relation, gamestate) {
+                Ok(relation) => relations.push(relation),
+                Err(e) => skipped.push(SkippedEntry::new(relation.path(), e)),
             }
         }
     }
+    Ok((relations, skipped))
+}
 
-    Ok(curr_val)
+fn get_relation_data(
+    country: &Node<'_>,
+    relation: &Node<'_>,
+    gamestate: &Node<'_>,
+) -> ImportResult<RelationData> {
+    let mut relation_data = RelationData::from_val(relation)?;
+    relation_data.owner = country.decode("name")?;
+    relation_data.country = get_country_names(gamestate, &relation.select("country")?)?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    relation_data.treaties = TREATIES
+        .iter()
+        .filter(|treaty| is_yes(relation, treaty))
+        .map(|treaty| String::from(*treaty))
+        .collect();
+    relation_data.rival = is_yes(relation, "is_rival");
+    relation_data.borders = is_yes(relation, "borders");
+    relation_data.contact = is_yes(relation, "contact");
+    Ok(relation_data)
 }
 
-fn get_resources(economy_module: &Val<'_>) -> Resources {
-    let get_resource = |res: ResourceClass| -> f64 {
-        if let Ok(val) = get_val_from_path(
-            PathBuf::from(format!("resources/{}", res.key())),
-            economy_module,
-        ) {
-            match val {
-                Val::Integer(a) => *a as f64,
-                Val::Decimal(a) => *a,
-                _ => panic!(),
-            }
-        } else {
-            0.0f64
+fn get_systems_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<SystemData>, Vec<SkippedEntry>)> {
+    let mut systems = vec![];
+    let mut skipped = vec![];
+    for system in gamestate.select("galactic_object/*")? {
+        if system.kind() != ValKind::Dict {
+            continue;
         }
+        match get_system_data(&system, gamestate) {
+            Ok(system) => systems.push(system),
+            Err(e) => skipped.push(SkippedEntry::new(system.path(), e)),
+        }
+    }
+    Ok((systems, skipped))
+}
+
+fn get_system_data(system: &Node<'_>, gamestate: &Node<'_>) -> ImportResult<SystemData> {
+    let mut system_data = SystemData::from_val(system)?;
+    system_data.id = get_id(system)?;
+    system_data.name = get_localised_name(system)?;
+    system_data.hyperlanes = system
+        .select("hyperlane/*/to")?
+        .iter()
+        .map(i64::from_val)
+        .collect::<ImportResult<_>>()?;
+    let owner = match system.decode_or::<i64>("starbase", NO_ID)? {
+        NO_ID => vec![],
+        starbase => gamestate.select(&format!("starbase_mgr/starbases/{}/owner", starbase))?,
     };
+    system_data.owner = get_country_names(gamestate, &owner)?.into_iter().next();
+    system_data.trade = TradeHubData::from_val(system)?;
+    Ok(system_data)
+}
+
+fn get_trade_routes_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<TradeRouteData>, Vec<SkippedEntry>)> {
+    let mut routes = vec![];
+    let mut skipped = vec![];
+    for route in gamestate.select("trade_routes_manager/routes/*")? {
+        if route.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_trade_route_data(&route, gamestate) {
+            Ok(route) => routes.push(route),
+            Err(e) => skipped.push(SkippedEntry::new(route.path(), e)),
+        }
+    }
+    Ok((routes, skipped))
+}
+
+fn get_trade_route_data(route: &Node<'_>, gamestate: &Node<'_>) -> ImportResult<TradeRouteData> {
+    let mut route_data = TradeRouteData::from_val(route)?;
+    route_data.id = get_id(route)?;
+    route_data.owner = get_country_names(gamestate, &[route.get("owner")?])?.remove(0);
+    route_data.path = route
+        .select("path/*/id")?
+        .iter()
+        .map(i64::from_val)
+        .collect::<ImportResult<_>>()?;
+    Ok(route_data)
+}
+
+fn get_megastructures_from_gamestate(
+    gamestate: &Node<'_>,
+) -> ImportResult<(Vec<MegastructureData>, Vec<SkippedEntry>)> {
+    let mut megastructures = vec![];
+    let mut skipped = vec![];
+    for megastructure in gamestate.select("megastructures/*")? {
+        if megastructure.kind() != ValKind::Dict {
+            continue;
+        }
+        match get_megastructure_data(&megastructure, gamestate) {
+            Ok(megastructure) => megastructures.push(megastructure),
+            Err(e) => skipped.push(SkippedEntry::new(megastructure.path(), e)),
+        }
+    }
+    Ok((megastructures, skipped))
+}
+
+fn get_megastructure_data(
+    megastructure: &Node<'_>,
+    gamestate: &Node<'_>,
+) -> ImportResult<MegastructureData> {
+    let mut megastructure_data = MegastructureData::from_val(megastructure)?;
+    megastructure_data.id = get_id(megastructure)?;
+    // Unowned megastructures (ruined gateways, abandoned ring worlds) either omit the owner or
+    // point at a country that does not exist.
+    if let Some(owner) = megastructure.select("owner")?.first() {
+        let name = gamestate.select(&format!("country/{}/name", owner.as_i64()?))?;
+        megastructure_data.owner = name.first().map(String::from_val).transpose()?;
+    }
+    Ok(megastructure_data)
+}
+
+fn is_yes(node: &Node<'_>, query: &str) -> bool {
+    matches!(node.decode::<String>(query).as_deref(), Ok("yes"))
+}
+
+fn get_id(node: &Node<'_>) -> ImportResult<i64> {
+    let id = node.path().rsplit('/').next().unwrap_or_default();
+    id.parse()
+        .map_err(|e: ParseIntError| DataImportError::InvalidValue {
+            path: String::from(node.path()),
+            message: format!("invalid id {:?}: {}", id, e),
+        })
+}
+
+fn get_localised_name(node: &Node<'_>) -> ImportResult<String> {
+    match node.decode::<String>("name") {
+        Err(DataImportError::UnexpectedKind { .. }) => node.decode("name/key"),
+        name => name,
+    }
+}
+
+fn get_country_names(gamestate: &Node<'_>, countries: &[Node<'_>]) -> ImportResult<Vec<String>> {
+    countries
+        .iter()
+        .map(|country| gamestate.decode(&format!("country/{}/name", country.as_i64()?)))
+        .collect()
+}
 
+from_val! {
     Resources {
-        energy: get_resource(ResourceClass::Energy),
-        minerals: get_resource(ResourceClass::Minerals),
-        food: get_resource(ResourceClass::Food),
-        physics_research: get_resource(ResourceClass::Physics),
-        society_research: get_resource(ResourceClass::Society),
-        engineering_research: get_resource(ResourceClass::Engineering),
-        influence: get_resource(ResourceClass::Influence),
-        unity: get_resource(ResourceClass::Unity),
-        consumer_goods: get_resource(ResourceClass::ConsumerGoods),
-        alloys: get_resource(ResourceClass::Alloys),
-        volatile_motes: get_resource(ResourceClass::Motes),
-        exotic_gases: get_resource(ResourceClass::Gasses),
-        rare_crystals: get_resource(ResourceClass::Crystals),
-        sr_living_metal: get_resource(ResourceClass::LivingMetal),
-        sr_zro: get_resource(ResourceClass::Zro),
-        sr_dark_matter: get_resource(ResourceClass::DarkMatter),
-    }
-}
-
-fn get_budget(budget: &Val) -> Budget {
-    let current_month_dict = get_val_from_path(PathBuf::from("current_month"), &budget).unwrap();
-    let last_month_dict = get_val_from_path(PathBuf::from("last_month"), &budget).unwrap();
-
-    let get_budget_val =
-        |key: BudgetComponent, val: &Val| -> HashMap<ResourceClass, Vec<(String, f64)>> {
-            get_budget_component_map(get_val_from_path(PathBuf::from(key.key()), val).unwrap())
-        };
+        energy: "energy" = 0.0,
+        minerals: "minerals" = 0.0,
+        food: "food" = 0.0,
+        physics_research: "physics_research" = 0.0,
+        society_research: "society_research" = 0.0,
+        engineering_research: "engineering_research" = 0.0,
+        influence: "influence" = 0.0,
+        unity: "unity" = 0.0,
+        consumer_goods: "consumer_goods" = 0.0,
+        alloys: "alloys" = 0.0,
+        volatile_motes: "volatile_motes" = 0.0,
+        exotic_gases: "exotic_gases" = 0.0,
+        rare_crystals: "rare_crystals" = 0.0,
+        sr_living_metal: "sr_living_metal" = 0.0,
+        sr_zro: "sr_zro" = 0.0,
+        sr_dark_matter: "sr_dark_matter" = 0.0,
+    }
+}
 
+from_val! {
     Budget {
-        income: get_budget_val(BudgetComponent::Income, current_month_dict),
-        expense: get_budget_val(BudgetComponent::Expenses, current_month_dict),
-        balance: get_budget_val(BudgetComponent::Balance, current_month_dict),
-        income_last_month: get_budget_val(BudgetComponent::Income, last_month_dict),
-        expense_last_month: get_budget_val(BudgetComponent::Expenses, last_month_dict),
-        balance_last_month: get_budget_val(BudgetComponent::Balance, last_month_dict),
-    }
-}
-
-fn get_budget_component_map(component: &Val<'_>) -> HashMap<ResourceClass, Vec<(String, f64)>> {
-    if let Val::Dict(sources) = component {
-        let map =
-            sources
-                .into_iter()
-                .fold(HashMap::new(), |mut map, (contributor, contributions)| {
-                    let contribitions_per_class = get_contributions_per_class(contributions);
-
-                    for (key, amount) in contribitions_per_class.into_iter() {
-                        map.entry(key)
-                            .or_insert(vec![])
-                            .push((String::from(*contributor), amount));
-                    }
-                    map
-                });
-        map
-    } else {
-        panic!()
+        income: "current_month/income",
+        expense: "current_month/expenses",
+        balance: "current_month/balance",
+        income_last_month: "last_month/income",
+        expense_last_month: "last_month/expenses",
+        balance_last_month: "last_month/balance",
+    }
+}
+
+from_val! {
+    EmpireData {
+        name: "name",
+        budget: "budget",
+        resources: "modules/standard_economy_module/resources" = Resources::default(),
+        planets,
+        demographics,
+        fleets,
+        tech,
+        leaders,
+        military_power: "military_power" = 0.0,
+        economy_power: "economy_power" = 0.0,
+        tech_power: "tech_power" = 0.0,
+        victory_score: "victory_score" = 0.0,
+        victory_rank: "victory_rank" = 0,
+        empire_size: "empire_size" = 0.0,
+        fleet_size: "fleet_size" = 0,
+        starbase_capacity: "starbase_capacity" = 0,
+        identity,
+        trade_value: "modules/standard_trade_routes_module/last_month" = 0.0,
+    }
+}
+
+from_val! {
+    EmpireIdentity {
+        government: "government/type" = String::new(),
+        authority: "government/authority" = String::new(),
+        civics: "government/civics" = vec![],
+        ethics,
+        origin: "government/origin" = String::new(),
+        policies: "active_policies" = vec![],
+        ruler: "ruler" = None,
+    }
+}
+
+from_val! {
+    PolicyData {
+        policy: "policy",
+        selected: "selected",
+        date: "date" = None,
+    }
+}
+
+from_val! {
+    PopData {
+        species,
+        job: "job" = None,
+        stratum: "category" = None,
+        ethic: "ethos/ethic" = None,
+        happiness: "happiness" = None,
+    }
+}
+
+from_val! {
+    PlanetData {
+        id,
+        name: "name",
+        class: "planet_class",
+        size: "planet_size",
+        owner: "owner",
+        controller: "controller",
+        pops: "num_sapient_pops" = 0,
+        districts,
+        buildings,
+        stability: "stability" = 0.0,
+        amenities: "amenities" = 0.0,
+        housing: "total_housing" = 0.0,
+        crime: "crime" = 0.0,
+    }
+}
+
+from_val! {
+    FleetData {
+        id,
+        name: "name",
+        ships,
+        classes,
+        military_power: "military_power" = 0.0,
+        system: "movement_manager/coordinate/origin",
+    }
+}
+
+from_val! {
+    LeaderData {
+        id,
+        name,
+        class: "class",
+        level: "level" = 1,
+        traits,
+        age: "age" = 0,
+        assignment: "location/type" = String::from("none"),
+        location: "location/id" = -1,
+        owner: "country",
+    }
+}
+
+from_val! {
+    WarData {
+        id,
+        name,
+        start_date: "start_date",
+        attackers,
+        defenders,
+        attacker_war_goal: "attacker_war_goal/type",
+        defender_war_goal: "defender_war_goal/type" = None,
+        attacker_exhaustion: "attacker_war_exhaustion" = 0.0,
+        defender_exhaustion: "defender_war_exhaustion" = 0.0,
+    }
+}
+
+from_val! {
+    TruceData {
+        id,
+        name,
+        start_date: "start_date",
+        truce_type: "truce_type" = None,
+        countries,
+    }
+}
+
+from_val! {
+    FederationData {
+        id,
+        name,
+        federation_type: "federation_progression/federation_type" = String::new(),
+        level: "federation_progression/levels" = 1,
+        cohesion: "federation_progression/cohesion" = 0.0,
+        leader,
+        members,
+    }
+}
+
+from_val! {
+    GalacticCommunity {
+        members,
+        council,
+        resolutions: "passed" = vec![],
+        voting: "voting" = None,
+    }
+}
+
+from_val! {
+    RelationData {
+        owner,
+        country,
+        opinion: "relation_current" = 0,
+        trust: "trust" = 0.0,
+        treaties,
+        rival,
+        borders,
+        contact,
+    }
+}
+
+from_val! {
+    SystemData {
+        id,
+        name,
+        x: "coordinate/x",
+        y: "coordinate/y",
+        star_class: "star_class" = String::new(),
+        hyperlanes,
+        owner,
+        trade,
+    }
+}
+
+from_val! {
+    TradeHubData {
+        collected: "trade_hub/collected" = 0.0,
+        collected_from: "trade_hub/collected_from" = vec![],
+        throughput: "trade_piracy/throughput" = 0.0,
+        piracy: "trade_piracy/total" = 0.0,
+    }
+}
+
+from_val! {
+    MegastructureData {
+        id,
+        megastructure_type: "type",
+        owner,
+        system: "coordinate/origin",
+    }
+}
+
+from_val! {
+    TradeRouteData {
+        id,
+        owner,
+        from: "from",
+        to: "to",
+        delivered: "delivered" = 0.0,
+        path,
+    }
+}
+
+from_val! {
+    ResearchData {
+        technology: "technology",
+        progress: "progress" = 0.0,
+    }
+}
+
+impl FromVal for HashMap<ResourceClass, Vec<(String, f64)>> {
+    fn from_val(component: &Node<'_>) -> ImportResult<Self> {
+        Ok(component.as_dict()?.into_iter().fold(
+            HashMap::new(),
+            |mut map, (contributor, contributions)| {
+                let contribitions_per_class = get_contributions_per_class(&contributions);
+
+                for (key, amount) in contribitions_per_class.into_iter() {
+                    map.entry(key)
+                        .or_insert(vec![])
+                        .push((String::from(contributor), amount));
+                }
+                map
+            },
+        ))
     }
 }
 
-fn get_contributions_per_class(contributions: &Val<'_>) -> Vec<(ResourceClass, f64)> {
+fn get_contributions_per_class(contributions: &Node<'_>) -> Vec<(ResourceClass, f64)> {
     ResourceClass::iter()
         .filter_map(|class| {
-            if let Ok(val) = get_val_from_path(PathBuf::from(class.key()), contributions) {
-                match val {
-                    Val::Decimal(d) => Some((class, *d)),
-                    Val::Integer(i) => Some((class, *i as f64)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
+            let amount = contributions.get(class.key()).ok()?.as_f64().ok()?;
+            Some((class, amount))
         })
         .collect()
 }
 
-fn get_empire_data(country: &Val<'_>) -> Result<EmpireData> {
-    let economy_module =
-        get_val_from_path(PathBuf::from("modules/standard_economy_module"), country)?;
-    let name = get_val_from_path(PathBuf::from("name"), country)?;
-    let budget = get_val_from_path(PathBuf::from("budget"), country)?;
+/// Planets, pops, fleets, ships and leaders that cannot be resolved are pushed to `skipped` and
+/// left out of the empire instead of failing it.
+fn get_empire_data(
+    country: &Node<'_>,
+    gamestate: &Node<'_>,
+    skipped: &mut Vec<SkippedEntry>,
+) -> ImportResult<Option<EmpireData>> {
+    match country.get("modules/standard_economy_module") {
+        Ok(_) => {}
+        Err(DataImportError::MissingKey { .. }) => return Ok(None),
+        Err(e) => return Err(e),
+    }
 
-    Ok(EmpireData {
-        name: String::from(get_string_contents(name)),
-        resources: get_resources(economy_module),
-        budget: get_budget(budget),
-    })
+    let mut empire = EmpireData::from_val(country)?;
+    for id in country.decode_or::<Vec<i64>>("owned_planets", vec![])? {
+        match get_planet_data(gamestate, id) {
+            Ok(planet) => empire.planets.push(planet),
+            Err(e) => skipped.push(SkippedEntry::new(&format!("planets/planet/{}", id), e)),
+        }
+    }
+    let planets: Vec<i64> = empire.planets.iter().map(|planet| planet.id).collect();
+    empire.demographics = get_demographics(gamestate, &planets, skipped)?;
+    empire.fleets = get_fleets(country, gamestate, skipped)?;
+    empire.tech = get_tech_status(country)?;
+    for id in country.decode_or::<Vec<i64>>("owned_leaders", vec![])? {
+        match get_leader_data(gamestate, id) {
+            Ok(leader) => empire.leaders.push(leader),
+            Err(e) => skipped.push(SkippedEntry::new(&format!("leaders/{}", id), e)),
+        }
+    }
+    empire.identity = get_empire_identity(country)?;
+    Ok(Some(empire))
 }
 
-fn get_dict_contents<'a>(val: &'a Val<'a>) -> &'a Vec<(&'a str, Val<'a>)> {
-    if let Val::Dict(kv) = val {
-        kv
-    } else {
-        unimplemented!("This should be a dict");
-    }
+fn get_empire_identity(country: &Node<'_>) -> ImportResult<EmpireIdentity> {
+    let mut identity = EmpireIdentity::from_val(country)?;
+    identity.ethics = country
+        .select("ethos/ethic[]")?
+        .iter()
+        .map(String::from_val)
+        .collect::<ImportResult<_>>()?;
+    Ok(identity)
 }
 
-fn get_array_contents<'a>(gamestate: &'a Val<'a>) -> &'a Vec<Val<'a>> {
-    if let Val::Array(arr) = gamestate {
-        arr
-    } else {
-        unimplemented!("This should be an array");
-    }
+fn get_planet_data(gamestate: &Node<'_>, id: i64) -> ImportResult<PlanetData> {
+    let planet = gamestate.get(&format!("planets/planet/{}", id))?;
+
+    let mut planet_data = PlanetData::from_val(&planet)?;
+    planet_data.id = id;
+    planet_data.districts = planet
+        .select("district[]")?
+        .iter()
+        .map(String::from_val)
+        .collect::<ImportResult<_>>()?;
+    planet_data.buildings = planet
+        .decode_or::<Vec<i64>>("buildings", vec![])?
+        .into_iter()
+        .map(|building| gamestate.decode(&format!("buildings/{}/type", building)))
+        .collect::<ImportResult<_>>()?;
+    Ok(planet_data)
 }
 
-fn get_string_contents<'a>(gamestate: &'a Val<'a>) -> &'a str {
-    if let Val::StringLiteral(str) = gamestate {
-        str
-    } else {
-        unimplemented!("This should be a StringLitteral");
+fn get_fleets(
+    country: &Node<'_>,
+    gamestate: &Node<'_>,
+    skipped: &mut Vec<SkippedEntry>,
+) -> ImportResult<Vec<FleetData>> {
+    let mut fleets = vec![];
+    for id in country.decode_or::<Vec<i64>>("owned_fleets", vec![])? {
+        match get_fleet_data(gamestate, id, skipped) {
+            Ok(Some(fleet)) => fleets.push(fleet),
+            Ok(None) => {}
+            Err(e) => skipped.push(SkippedEntry::new(&format!("fleet/{}", id), e)),
+        }
     }
+    Ok(fleets)
 }
 
-fn get_decimal_contents<'a>(gamestate: &'a Val<'a>) -> f64 {
-    if let Val::Decimal(dec) = gamestate {
-        *dec
-    } else {
-        unimplemented!("This should be a Decimal");
+fn get_fleet_data(
+    gamestate: &Node<'_>,
+    id: i64,
+    skipped: &mut Vec<SkippedEntry>,
+) -> ImportResult<Option<FleetData>> {
+    let fleet = gamestate.get(&format!("fleet/{}", id))?;
+    if !fleet.select("station")?.is_empty() {
+        return Ok(None);
+    }
+
+    let mut fleet_data = FleetData::from_val(&fleet)?;
+    fleet_data.id = id;
+    for ship in fleet.decode_or::<Vec<i64>>("ships", vec![])? {
+        match get_ship_class(gamestate, ship) {
+            Ok(class) => {
+                fleet_data.ships += 1;
+                *fleet_data.classes.entry(class).or_default() += 1;
+            }
+            Err(e) => skipped.push(SkippedEntry::new(&format!("ships/{}", ship), e)),
+        }
     }
+    Ok(Some(fleet_data))
+}
+
+fn get_ship_class(gamestate: &Node<'_>, ship: i64) -> ImportResult<String> {
+    let design = gamestate.decode::<i64>(&format!("ships/{}/ship_design", ship))?;
+    gamestate.decode(&format!("ship_design/{}/ship_size", design))
+}
+
+fn get_leader_data(gamestate: &Node<'_>, id: i64) -> ImportResult<LeaderData> {
+    let leader = gamestate.get(&format!("leaders/{}", id))?;
+
+    let mut leader_data = LeaderData::from_val(&leader)?;
+    leader_data.id = id;
+    leader_data.name = leader
+        .select("name/first_name")?
+        .iter()
+        .chain(leader.select("name/second_name")?.iter())
+        .map(String::from_val)
+        .collect::<ImportResult<Vec<_>>>()?
+        .join(" ");
+    leader_data.traits = leader
+        .select(&format!("roles/{}/trait[]", leader_data.class))?
+        .iter()
+        .map(String::from_val)
+        .collect::<ImportResult<_>>()?;
+    Ok(leader_data)
 }
 
-fn get_integer_contents<'a>(gamestate: &'a Val<'a>) -> i64 {
-    if let Val::Integer(int) = gamestate {
-        *int
-    } else {
-        unimplemented!("This should be an Integer");
+fn get_tech_status(country: &Node<'_>) -> ImportResult<TechStatus> {
+    let tech_status = match country.get("tech_status") {
+        Ok(tech_status) => tech_status,
+        Err(DataImportError::MissingKey { .. }) => return Ok(TechStatus::default()),
+        Err(e) => return Err(e),
+    };
+
+    let technologies = tech_status.select("technology[]")?;
+    let levels = tech_status.select("level[]")?;
+    let mut researched = HashMap::new();
+    for (index, technology) in technologies.iter().enumerate() {
+        let level = match levels.get(index) {
+            Some(level) => level.as_i64()?,
+            None => 1,
+        };
+        researched.insert(String::from(technology.as_str()?), level);
     }
+
+    let get_research = |queue: &str| -> ImportResult<Option<ResearchData>> {
+        match tech_status.select(&format!("{}[]", queue))?.first() {
+            Some(research) => Ok(Some(ResearchData::from_val(research)?)),
+            None => Ok(None),
+        }
+    };
+
+    Ok(TechStatus {
+        researched,
+        physics: get_research("physics_queue")?,
+        society: get_research("society_queue")?,
+        engineering: get_research("engineering_queue")?,
+    })
+}
+
+struct PopData {
+    species: String,
+    job: Option<String>,
+    stratum: Option<String>,
+    ethic: Option<String>,
+    happiness: Option<f64>,
 }
 
-fn get_number_contents<'a>(gamestate: &'a Val<'a>) -> f64 {
-    match gamestate {
-        Val::Decimal(f) => *f,
-        Val::Integer(i) => *i as f64,
-        _ => unimplemented!("this should be a number"),
+fn get_demographics(
+    gamestate: &Node<'_>,
+    owned_planets: &[i64],
+    skipped: &mut Vec<SkippedEntry>,
+) -> ImportResult<Demographics> {
+    let mut demographics = Demographics::default();
+    let mut happiness = vec![];
+
+    for planet in owned_planets {
+        for id in
+            gamestate.decode_or::<Vec<i64>>(&format!("planets/planet/{}/pop", planet), vec![])?
+        {
+            let pop = match get_pop_data(gamestate, id) {
+                Ok(pop) => pop,
+                Err(e) => {
+                    skipped.push(SkippedEntry::new(&format!("pop/{}", id), e));
+                    continue;
+                }
+            };
+            *demographics.species.entry(pop.species).or_default() += 1;
+            if let Some(job) = pop.job {
+                *demographics.jobs.entry(job).or_default() += 1;
+            }
+            if let Some(stratum) = pop.stratum {
+                *demographics.strata.entry(stratum).or_default() += 1;
+            }
+            if let Some(ethic) = pop.ethic {
+                *demographics.ethics.entry(ethic).or_default() += 1;
+            }
+            if let Some(pop_happiness) = pop.happiness {
+                happiness.push(pop_happiness);
+            }
+        }
+    }
+
+    if !happiness.is_empty() {
+        demographics.happiness = happiness.iter().sum::<f64>() / happiness.len() as f64;
     }
+    Ok(demographics)
+}
+
+fn get_pop_data(gamestate: &Node<'_>, id: i64) -> ImportResult<PopData> {
+    let pop = gamestate.get(&format!("pop/{}", id))?;
+
+    let mut pop_data = PopData::from_val(&pop)?;
+    let species = pop.decode::<i64>("species")?;
+    pop_data.species =
+        gamestate.decode_or(&format!("species_db/{}/name", species), species.to_string())?;
+    Ok(pop_data)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, fs, path::PathBuf};
+    use std::{collections::HashMap, fs, path::PathBuf, str::FromStr};
 
     use clausewitz_parser::root;
     use data_model::{Budget, Resources};
@@ -357,7 +1069,7 @@ mod tests {
 
         let (_, parse) = root(&meta_string).unwrap();
 
-        let dlcs = get_required_dlcs_from_meta(&parse);
+        let dlcs = get_required_dlcs_from_meta(&Node::root(&parse)).unwrap();
 
         assert_eq!(
             dlcs,
@@ -565,7 +1277,9 @@ mod tests {
 
         let (_, parse) = root(empire_string).unwrap();
 
-        let empire = get_empire_data(&parse).unwrap();
+        let empire = get_empire_data(&Node::root(&parse), &Node::root(&parse), &mut vec![])
+            .unwrap()
+            .unwrap();
         assert_eq!(
             empire,
             EmpireData {
@@ -595,13 +1309,19 @@ mod tests {
                     income_last_month: expected_income.clone(),
                     expense_last_month: expected_expense.clone(),
                     balance_last_month: expected_balance.clone()
-                }
+                },
+                planets: vec![],
+                demographics: Demographics::default(),
+                fleets: vec![],
+                tech: TechStatus::default(),
+                leaders: vec![],
+                ..EmpireData::default()
             }
         );
     }
 
     #[test]
-    fn get_resources__given_economy_module__returns_all_resources() {
+    fn from_val__given_resources__returns_all_resources() {
         let module_entry = r###"standard_economy_module={
             resources={
                 energy=11484.2
@@ -626,7 +1346,9 @@ mod tests {
 
         if let Val::Dict(entries) = val {
             let (_, economy_module) = entries.into_iter().next().unwrap();
-            let resources = get_resources(&economy_module);
+            let resources =
+                Resources::from_val(&Node::root(&economy_module).get("resources").unwrap())
+                    .unwrap();
             assert_eq!(
                 resources,
                 Resources {
@@ -654,7 +1376,7 @@ mod tests {
     }
 
     #[test]
-    fn get_budget__given_budget__returns_budget() {
+    fn from_val__given_budget__returns_budget() {
         let home = std::env::var("HOME").unwrap();
         let ext = "Dev/stellarust/res/test_data/campaign_raw/unitednationsofearth_-15512622/autosave_2200.02.01/empire_budget";
         let empire_budget_path = PathBuf::from_iter(vec![home.as_str(), ext]);
@@ -664,7 +1386,7 @@ mod tests {
 
         if let Val::Dict(entries) = parse {
             let (_, budget_dict) = entries.into_iter().next().unwrap();
-            let _budget = get_budget(&budget_dict);
+            let _budget = Budget::from_val(&Node::root(&budget_dict)).unwrap();
         } else {
             panic!()
         }
@@ -723,7 +1445,8 @@ mod tests {
             panic!()
         };
 
-        let income = get_budget_component_map(&income);
+        let income: HashMap<ResourceClass, Vec<(String, f64)>> =
+            FromVal::from_val(&Node::root(&income)).unwrap();
         assert_eq!(income, map);
     }
 
@@ -780,7 +1503,8 @@ mod tests {
             panic!()
         };
 
-        let income = get_budget_component_map(&income);
+        let income: HashMap<ResourceClass, Vec<(String, f64)>> =
+            FromVal::from_val(&Node::root(&income)).unwrap();
         assert_eq!(income, map);
     }
 
@@ -790,8 +1514,1503 @@ mod tests {
 
         let (_, dict) = root(text).unwrap();
 
-        let name = get_name_from_meta(&dict);
+        let name = get_name_from_meta(&Node::root(&dict)).unwrap();
 
         assert_eq!(name, "Eat My Shorts");
     }
+
+    #[test]
+    fn get_date_from_meta__contains_keyvalue_date__returns_value() {
+        let text = "date=\"2200.02.01\"\n";
+
+        let (_, dict) = root(text).unwrap();
+
+        let date = get_date_from_meta(&Node::root(&dict)).unwrap();
+
+        assert_eq!(date, StellarisDate::new(2200, 2, 1).unwrap());
+    }
+
+    #[test]
+    fn get_date_from_meta__contains_invalid_date__returns_invalid_value_error() {
+        let text = "date=\"2200.13.01\"\n";
+
+        let (_, dict) = root(text).unwrap();
+
+        let error = get_date_from_meta(&Node::root(&dict)).unwrap_err();
+
+        assert!(matches!(error, DataImportError::InvalidValue { .. }));
+        assert_eq!(error.path(), "date");
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_malformed_country__skips_and_reports_country() {
+        let gamestate = r###"
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                modules={
+                    standard_economy_module={
+                        resources={
+                            energy=100
+                        }
+                    }
+                }
+            }
+            1={
+                name="Malformed Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                modules={
+                    standard_economy_module={
+                        resources={
+                            energy="lots"
+                        }
+                    }
+                }
+            }
+            2={
+                name="Global Event Country"
+                modules={ }
+            }
+            3=none
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert_eq!(
+            empires
+                .iter()
+                .map(|empire| empire.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Valid Empire"]
+        );
+        assert_eq!(
+            skipped,
+            vec![SkippedEntry {
+                entry: String::from("country/1"),
+                error: DataImportError::UnexpectedKind {
+                    path: String::from(
+                        "country/1/modules/standard_economy_module/resources/energy"
+                    ),
+                    expected: ValKind::Number,
+                    actual: ValKind::StringLiteral,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn get_empire_data__given_malformed_budget__returns_error_with_budget_path() {
+        let country = r###"
+        name="Broken Budget"
+        budget={
+            current_month={ income={ } expenses=0 balance={ } }
+            last_month={ income={ } expenses={ } balance={ } }
+        }
+        modules={ standard_economy_module={ resources={ } } }
+        "###;
+
+        let (_, parse) = root(country).unwrap();
+
+        let error =
+            get_empire_data(&Node::root(&parse), &Node::root(&parse), &mut vec![]).unwrap_err();
+
+        assert_eq!(error.path(), "budget/current_month/expenses");
+        assert_eq!(
+            error.to_string(),
+            "budget/current_month/expenses: expected Dict but found Integer"
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_owned_planets__attaches_planet_data() {
+        let gamestate = r###"
+        planets={
+            planet={
+                0={
+                    name="Sol"
+                    planet_class="pc_g_star"
+                    planet_size=30
+                }
+                1={
+                    name="Earth"
+                    planet_class="pc_continental"
+                    planet_size=18
+                    owner=0
+                    controller=0
+                    buildings={ 1 0 }
+                    district="district_city"
+                    district="district_farming"
+                    stability=77.2672
+                    crime=3
+                    amenities=51
+                    total_housing=40
+                    num_sapient_pops=32
+                }
+                2={
+                    name="Mars"
+                    planet_class="pc_arid"
+                    planet_size=12
+                    owner=0
+                    controller=1
+                }
+            }
+        }
+        buildings={
+            0={
+                type="building_capital"
+                position=0
+            }
+            1={
+                type="building_research_lab_1"
+                position=1
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_planets={ 1 2 }
+                modules={
+                    standard_economy_module={
+                        resources={
+                            energy=100
+                        }
+                    }
+                }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            empires[0].planets,
+            vec![
+                PlanetData {
+                    id: 1,
+                    name: String::from("Earth"),
+                    class: String::from("pc_continental"),
+                    size: 18,
+                    owner: 0,
+                    controller: 0,
+                    pops: 32,
+                    districts: vec![
+                        String::from("district_city"),
+                        String::from("district_farming")
+                    ],
+                    buildings: vec![
+                        String::from("building_research_lab_1"),
+                        String::from("building_capital")
+                    ],
+                    stability: 77.2672,
+                    amenities: 51.0,
+                    housing: 40.0,
+                    crime: 3.0,
+                },
+                PlanetData {
+                    id: 2,
+                    name: String::from("Mars"),
+                    class: String::from("pc_arid"),
+                    size: 12,
+                    owner: 0,
+                    controller: 1,
+                    ..PlanetData::default()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_unknown_owned_planet__skips_planet_and_keeps_country() {
+        let gamestate = r###"
+        planets={
+            planet={
+                0={ name="Sol" planet_class="pc_g_star" planet_size=30 owner=0 controller=0 }
+            }
+        }
+        country={
+            0={
+                name="Lost Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_planets={ 0 7 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert_eq!(empires.len(), 1);
+        assert_eq!(
+            empires[0]
+                .planets
+                .iter()
+                .map(|planet| planet.id)
+                .collect::<Vec<_>>(),
+            vec![0]
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].entry, "planets/planet/7");
+        assert_eq!(skipped[0].error.path(), "planets/planet/7");
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_unknown_pop__skips_pop_and_keeps_country() {
+        let gamestate = r###"
+        species_db={ 0={ name="Human" } }
+        pop={ 0={ species=0 job="farmer" category="worker" } }
+        planets={
+            planet={
+                0={
+                    name="Earth"
+                    planet_class="pc_continental"
+                    planet_size=18
+                    owner=0
+                    controller=0
+                    pop={ 0 7 }
+                }
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_planets={ 0 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert_eq!(empires.len(), 1);
+        assert_eq!(
+            empires[0].demographics.species,
+            HashMap::from([(String::from("Human"), 1)])
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].entry, "pop/7");
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_pops_on_owned_planets__counts_demographics() {
+        let gamestate = r###"
+        species_db={
+            0={
+                name="Human"
+            }
+            1={
+                name="Yaanari"
+            }
+        }
+        pop={
+            0={
+                species=0
+                ethos={ ethic="ethic_xenophile" }
+                job="administrator"
+                category="ruler"
+                planet=1
+                happiness=0.9
+            }
+            1={
+                species=0
+                ethos={ ethic="ethic_egalitarian" }
+                job="farmer"
+                category="worker"
+                planet=1
+                happiness=0.5
+            }
+            2={
+                species=1
+                ethos={ ethic="ethic_xenophile" }
+                category="worker"
+                planet=2
+                happiness=0.4
+            }
+            3={
+                species=1
+                job="farmer"
+                category="worker"
+                planet=0
+                happiness=0.1
+            }
+        }
+        planets={
+            planet={
+                0={
+                    name="Yaanar"
+                    planet_class="pc_arctic"
+                    planet_size=16
+                    owner=1
+                    controller=1
+                    pop={ 3 }
+                }
+                1={
+                    name="Earth"
+                    planet_class="pc_continental"
+                    planet_size=18
+                    owner=0
+                    controller=0
+                    pop={ 0 1 }
+                }
+                2={
+                    name="Mars"
+                    planet_class="pc_arid"
+                    planet_size=12
+                    owner=0
+                    controller=0
+                    pop={ 2 }
+                }
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_planets={ 1 2 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        let demographics = &empires[0].demographics;
+        assert_eq!(
+            demographics.species,
+            HashMap::from([(String::from("Human"), 2), (String::from("Yaanari"), 1)])
+        );
+        assert_eq!(
+            demographics.jobs,
+            HashMap::from([
+                (String::from("administrator"), 1),
+                (String::from("farmer"), 1)
+            ])
+        );
+        assert_eq!(
+            demographics.strata,
+            HashMap::from([(String::from("ruler"), 1), (String::from("worker"), 2)])
+        );
+        assert_eq!(
+            demographics.ethics,
+            HashMap::from([
+                (String::from("ethic_xenophile"), 2),
+                (String::from("ethic_egalitarian"), 1)
+            ])
+        );
+        assert!((demographics.happiness - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_owned_fleets__attaches_military_fleets() {
+        let gamestate = r###"
+        ship_design={
+            0={
+                name="Scorpion"
+                ship_size="corvette"
+            }
+            1={
+                name="Hannover"
+                ship_size="destroyer"
+            }
+            2={
+                name="Sol Station"
+                ship_size="starbase_starport"
+            }
+        }
+        ships={
+            0={
+                fleet=0
+                ship_design=2
+            }
+            1={
+                fleet=1
+                ship_design=0
+            }
+            2={
+                fleet=1
+                ship_design=1
+            }
+            3={
+                fleet=1
+                ship_design=0
+            }
+        }
+        fleet={
+            0={
+                name="Starbase"
+                ships={ 0 }
+                owner=0
+                station=yes
+                military_power=500
+                movement_manager={ coordinate={ x=0 y=0 origin=169 } }
+            }
+            1={
+                name="Home Fleet"
+                ships={ 1 2 3 }
+                owner=0
+                mobile=yes
+                military_power=253.06961
+                movement_manager={ coordinate={ x=21.6 y=-21.6 origin=169 } }
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_fleets={ 0 1 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            empires[0].fleets,
+            vec![FleetData {
+                id: 1,
+                name: String::from("Home Fleet"),
+                ships: 3,
+                classes: HashMap::from([
+                    (String::from("corvette"), 2),
+                    (String::from("destroyer"), 1)
+                ]),
+                military_power: 253.06961,
+                system: 169,
+            }]
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_unknown_fleet_and_ship__skips_them_and_keeps_country() {
+        let gamestate = r###"
+        ship_design={ 0={ name="Scorpion" ship_size="corvette" } }
+        ships={
+            0={ fleet=0 ship_design=0 }
+            1={ fleet=0 ship_design=9 }
+        }
+        fleet={
+            0={
+                name="Home Fleet"
+                ships={ 0 1 5 }
+                owner=0
+                military_power=100
+                movement_manager={ coordinate={ x=0 y=0 origin=169 } }
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_fleets={ 0 4 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert_eq!(empires.len(), 1);
+        assert_eq!(empires[0].fleets.len(), 1);
+        assert_eq!(empires[0].fleets[0].ships, 1);
+        assert_eq!(
+            empires[0].fleets[0].classes,
+            HashMap::from([(String::from("corvette"), 1)])
+        );
+        assert_eq!(
+            skipped
+                .iter()
+                .map(|skipped| skipped.entry.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ships/1", "ships/5", "fleet/4"]
+        );
+    }
+
+    #[test]
+    fn get_tech_status__given_empire_fixture__returns_researched_technologies() {
+        let home = std::env::var("HOME").unwrap();
+        let ext = "Dev/stellarust/res/test_data/campaign_raw/unitednationsofearth_-15512622/autosave_2200.02.01/empire";
+        let empire_path = PathBuf::from_iter(vec![home.as_str(), ext]);
+        let empire_string = fs::read_to_string(empire_path).unwrap();
+
+        let (_, parse) = root(&empire_string).unwrap();
+
+        let tech = get_tech_status(&Node::root(&parse)).unwrap();
+
+        assert_eq!(tech.researched.get("tech_corvettes"), Some(&1));
+        assert_eq!(tech.researched.get("tech_lasers_1"), Some(&1));
+        assert!(tech.researched.values().all(|level| *level >= 1));
+    }
+
+    #[test]
+    fn get_tech_status__given_research_queues__returns_current_research_per_area() {
+        let country = r###"
+        tech_status={
+            technology="tech_lasers_1"
+            level=1
+            technology="tech_repeatable_weapon_type_energy_damage"
+            level=3
+            physics_queue={
+                {
+                    progress=22.47168
+                    technology="tech_lasers_2"
+                    date="2200.01.01"
+                }
+            }
+            society_queue={
+            }
+            engineering_queue={
+                {
+                    progress=23.52528
+                    technology="tech_powered_exoskeletons"
+                    date="2200.01.01"
+                }
+                {
+                    progress=0
+                    technology="tech_mining_network_2"
+                    date="2200.01.01"
+                }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(country).unwrap();
+
+        let tech = get_tech_status(&Node::root(&parse)).unwrap();
+
+        assert_eq!(
+            tech,
+            TechStatus {
+                researched: HashMap::from([
+                    (String::from("tech_lasers_1"), 1),
+                    (String::from("tech_repeatable_weapon_type_energy_damage"), 3)
+                ]),
+                physics: Some(ResearchData {
+                    technology: String::from("tech_lasers_2"),
+                    progress: 22.47168
+                }),
+                society: None,
+                engineering: Some(ResearchData {
+                    technology: String::from("tech_powered_exoskeletons"),
+                    progress: 23.52528
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_unknown_leader__skips_leader_and_keeps_country() {
+        let gamestate = r###"
+        leaders={
+            0={ name={ first_name="Cerulean" } country=0 class="scientist" level=1 age=41 }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_leaders={ 0 6 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert_eq!(empires.len(), 1);
+        assert_eq!(
+            empires[0]
+                .leaders
+                .iter()
+                .map(|leader| leader.id)
+                .collect::<Vec<_>>(),
+            vec![0]
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].entry, "leaders/6");
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_owned_leaders__attaches_leaders() {
+        let gamestate = r###"
+        leaders={
+            0={
+                name={
+                    first_name="Dolores"
+                    second_name="Muwanga"
+                }
+                country=0
+                class="ruler"
+                level=2
+                location={
+                    type=planet
+                    area=none
+                    assignment=none
+                    id=3
+                }
+                age=28
+                roles={
+                    admiral={
+                        trait="leader_trait_cautious"
+                    }
+                    ruler={
+                        trait="trait_ruler_expansionist"
+                        trait="trait_ruler_space_miner"
+                    }
+                }
+            }
+            1={
+                name={
+                    first_name="Cerulean"
+                }
+                country=0
+                class="scientist"
+                level=1
+                age=41
+                roles={
+                    scientist={
+                        trait="leader_trait_expertise_industry"
+                    }
+                }
+            }
+        }
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                owned_leaders={ 0 1 }
+                modules={ standard_economy_module={ resources={ } } }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            empires[0].leaders,
+            vec![
+                LeaderData {
+                    id: 0,
+                    name: String::from("Dolores Muwanga"),
+                    class: String::from("ruler"),
+                    level: 2,
+                    traits: vec![
+                        String::from("trait_ruler_expansionist"),
+                        String::from("trait_ruler_space_miner")
+                    ],
+                    age: 28,
+                    assignment: String::from("planet"),
+                    location: 3,
+                    owner: 0,
+                },
+                LeaderData {
+                    id: 1,
+                    name: String::from("Cerulean"),
+                    class: String::from("scientist"),
+                    level: 1,
+                    traits: vec![String::from("leader_trait_expertise_industry")],
+                    age: 41,
+                    assignment: String::from("none"),
+                    location: -1,
+                    owner: 0,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_government__reads_identity() {
+        let gamestate = r###"
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                modules={ standard_economy_module={ resources={ } } }
+                ethos={
+                    ethic="ethic_fanatic_egalitarian"
+                    ethic="ethic_xenophile"
+                }
+                government={
+                    type="gov_representative_democracy"
+                    authority="auth_democratic"
+                    civics={
+                        "civic_beacon_of_liberty"
+                        "civic_idealistic_foundation"
+                    }
+                    origin="origin_default"
+                }
+                active_policies={
+                    {
+                        policy="diplomatic_stance"
+                        selected="diplo_stance_expansionist"
+                    }
+                    {
+                        policy="robot_pop_policy"
+                        selected="robot_pops_outlawed"
+                        date="2200.01.01"
+                    }
+                }
+                ruler=4
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            empires[0].identity,
+            EmpireIdentity {
+                government: String::from("gov_representative_democracy"),
+                authority: String::from("auth_democratic"),
+                civics: vec![
+                    String::from("civic_beacon_of_liberty"),
+                    String::from("civic_idealistic_foundation")
+                ],
+                ethics: vec![
+                    String::from("ethic_fanatic_egalitarian"),
+                    String::from("ethic_xenophile")
+                ],
+                origin: String::from("origin_default"),
+                policies: vec![
+                    PolicyData {
+                        policy: String::from("diplomatic_stance"),
+                        selected: String::from("diplo_stance_expansionist"),
+                        date: None,
+                    },
+                    PolicyData {
+                        policy: String::from("robot_pop_policy"),
+                        selected: String::from("robot_pops_outlawed"),
+                        date: Some(StellarisDate::from_str("2200.01.01").unwrap()),
+                    }
+                ],
+                ruler: Some(4),
+            }
+        );
+    }
+
+    #[test]
+    fn get_empires_from_gamestate__given_scores__reads_scores_and_trade_value() {
+        let gamestate = r###"
+        country={
+            0={
+                name="Valid Empire"
+                budget={
+                    current_month={ income={ } expenses={ } balance={ } }
+                    last_month={ income={ } expenses={ } balance={ } }
+                }
+                modules={
+                    standard_economy_module={ resources={ } }
+                    standard_trade_routes_module={ last_month=35.55054 }
+                }
+                military_power=107.58059
+                economy_power=1.00000
+                victory_rank=6
+                victory_score=185
+                tech_power=240.00000
+                fleet_size=3
+                empire_size=33
+                starbase_capacity=3
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (empires, skipped) = get_empires_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        let empire = &empires[0];
+        assert_eq!(empire.military_power, 107.58059);
+        assert_eq!(empire.economy_power, 1.0);
+        assert_eq!(empire.tech_power, 240.0);
+        assert_eq!(empire.victory_score, 185.0);
+        assert_eq!(empire.victory_rank, 6);
+        assert_eq!(empire.empire_size, 33.0);
+        assert_eq!(empire.fleet_size, 3);
+        assert_eq!(empire.starbase_capacity, 3);
+        assert_eq!(empire.trade_value, 35.55054);
+    }
+
+    #[test]
+    fn get_wars_from_gamestate__given_wars__resolves_participants_and_war_goals() {
+        let gamestate = r###"
+        war={
+            0={
+                name={
+                    key="war_vs_adjective"
+                }
+                start_date="2230.05.01"
+                attackers={
+                    {
+                        call_type=primary
+                        country=0
+                    }
+                }
+                defenders={
+                    {
+                        call_type=primary
+                        country=1
+                    }
+                    {
+                        call_type=defensive
+                        country=2
+                    }
+                }
+                attacker_war_goal={
+                    type="wg_conquest"
+                }
+                attacker_war_exhaustion=0.12
+                defender_war_exhaustion=0.34
+            }
+            1=none
+        }
+        country={
+            0={ name="United Nations of Earth" }
+            1={ name="Yaanari Imperium" }
+            2={ name="Scyldari Confederacy" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (wars, skipped) = get_wars_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            wars,
+            vec![WarData {
+                id: 0,
+                name: String::from("war_vs_adjective"),
+                start_date: StellarisDate::new(2230, 5, 1).unwrap(),
+                attackers: vec![String::from("United Nations of Earth")],
+                defenders: vec![
+                    String::from("Yaanari Imperium"),
+                    String::from("Scyldari Confederacy")
+                ],
+                attacker_war_goal: String::from("wg_conquest"),
+                defender_war_goal: None,
+                attacker_exhaustion: 0.12,
+                defender_exhaustion: 0.34,
+            }]
+        );
+    }
+
+    #[test]
+    fn get_wars_from_gamestate__given_war_with_unknown_country__skips_and_reports_war() {
+        let gamestate = r###"
+        war={
+            0={
+                name="Lost War"
+                start_date="2230.05.01"
+                attackers={ { country=7 } }
+                defenders={ }
+                attacker_war_goal={ type="wg_humiliation" }
+            }
+        }
+        country={ }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (wars, skipped) = get_wars_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(wars.is_empty());
+        assert_eq!(skipped[0].entry, "war/0");
+        assert_eq!(skipped[0].error.path(), "country/7");
+    }
+
+    #[test]
+    fn get_wars_from_gamestate__given_war_with_invalid_id__skips_and_reports_war() {
+        let gamestate = r###"
+        war={
+            first={
+                name="Unnumbered War"
+                start_date="2230.05.01"
+                attackers={ }
+                defenders={ }
+                attacker_war_goal={ type="wg_humiliation" }
+            }
+        }
+        country={ }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (wars, skipped) = get_wars_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(wars.is_empty());
+        assert_eq!(skipped[0].entry, "war/first");
+        assert!(matches!(
+            skipped[0].error,
+            DataImportError::InvalidValue { .. }
+        ));
+    }
+    #[test]
+    fn get_truces_from_gamestate__given_truce__resolves_parties_from_relations() {
+        let gamestate = r###"
+        truce={
+            0={
+                name={
+                    key="war_vs_adjective"
+                }
+                start_date="2235.01.01"
+                truce_type=war
+            }
+            1=none
+        }
+        country={
+            0={
+                name="United Nations of Earth"
+                relations_manager={
+                    relation={
+                        owner=0
+                        country=1
+                        truce=0
+                    }
+                    relation={
+                        owner=0
+                        country=2
+                    }
+                }
+            }
+            1={
+                name="Yaanari Imperium"
+                relations_manager={
+                    relation={
+                        owner=1
+                        country=0
+                        truce=0
+                    }
+                }
+            }
+            2={
+                name="Scyldari Confederacy"
+                relations_manager={ }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (truces, skipped) = get_truces_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            truces,
+            vec![TruceData {
+                id: 0,
+                name: String::from("war_vs_adjective"),
+                start_date: StellarisDate::new(2235, 1, 1).unwrap(),
+                truce_type: Some(String::from("war")),
+                countries: vec![
+                    String::from("United Nations of Earth"),
+                    String::from("Yaanari Imperium")
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn get_federations_from_gamestate__given_federation__resolves_members_and_progression() {
+        let gamestate = r###"
+        federation={
+            0={
+                name={
+                    key="Sol Federation"
+                }
+                members={ 0 1 }
+                leader=0
+                federation_progression={
+                    federation_type="research_federation"
+                    levels=2
+                    cohesion=46.5
+                }
+            }
+        }
+        country={
+            0={ name="United Nations of Earth" }
+            1={ name="Yaanari Imperium" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (federations, skipped) = get_federations_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            federations,
+            vec![FederationData {
+                id: 0,
+                name: String::from("Sol Federation"),
+                federation_type: String::from("research_federation"),
+                level: 2,
+                cohesion: 46.5,
+                leader: Some(String::from("United Nations of Earth")),
+                members: vec![
+                    String::from("United Nations of Earth"),
+                    String::from("Yaanari Imperium")
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn get_galactic_community__given_community__resolves_members_council_and_resolutions() {
+        let gamestate = r###"
+        galactic_community={
+            members={ 0 1 }
+            council={ 1 }
+            passed={ "resolution_greatergood_balance_in_the_middle" }
+            voting="resolution_mutualdefense_defense_pact"
+            election=180
+        }
+        country={
+            0={ name="United Nations of Earth" }
+            1={ name="Yaanari Imperium" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let galactic_community = get_galactic_community(&Node::root(&parse)).unwrap();
+
+        assert_eq!(
+            galactic_community,
+            GalacticCommunity {
+                members: vec![
+                    String::from("United Nations of Earth"),
+                    String::from("Yaanari Imperium")
+                ],
+                council: vec![String::from("Yaanari Imperium")],
+                resolutions: vec![String::from("resolution_greatergood_balance_in_the_middle")],
+                voting: Some(String::from("resolution_mutualdefense_defense_pact")),
+            }
+        );
+    }
+
+    #[test]
+    fn get_market__given_fluctuations_and_trades__returns_prices_and_traded_amounts() {
+        let gamestate = r###"
+        market={
+            enabled=yes
+            fluctuations={ 0 0 -12.5 0 0 0 0 0 0 0 25 0 0 0 0 0 0 }
+            id={ 0 1 }
+            resources_bought={
+                amount={
+                    { 0 0 0 0 0 0 0 0 0 0 100 0 0 0 0 0 0 }
+                    { 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 }
+                }
+            }
+            resources_sold={
+                amount={
+                    { 0 0 1500 0 0 0 0 0 0 0 0 0 0 0 0 0 0 }
+                }
+            }
+            next_monthly_trade_item_id=0
+            country=4294967295
+        }
+        country={
+            0={ name="United Nations of Earth" }
+            1={ name="Yaanari Imperium" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let market = get_market(&Node::root(&parse)).unwrap();
+
+        assert_eq!(market.resources.len(), 10);
+        assert_eq!(
+            market.resources[0],
+            MarketResourceData {
+                resource: ResourceClass::Minerals,
+                fluctuation: -12.5,
+                price: 0.875,
+            }
+        );
+        assert_eq!(
+            market.resources[3],
+            MarketResourceData {
+                resource: ResourceClass::Alloys,
+                fluctuation: 25.0,
+                price: 5.0,
+            }
+        );
+        assert_eq!(
+            market.trades,
+            vec![
+                MarketTradeData {
+                    country: String::from("United Nations of Earth"),
+                    resource: ResourceClass::Minerals,
+                    bought: 0.0,
+                    sold: 1500.0,
+                },
+                MarketTradeData {
+                    country: String::from("United Nations of Earth"),
+                    resource: ResourceClass::Alloys,
+                    bought: 100.0,
+                    sold: 0.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_market__given_market_without_fluctuations__returns_empty_market() {
+        let gamestate = r###"
+        market={
+            next_monthly_trade_item_id=0
+            country=4294967295
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let market = get_market(&Node::root(&parse)).unwrap();
+
+        assert_eq!(market, MarketData::default());
+    }
+
+    #[test]
+    fn get_relations_from_gamestate__given_relations_manager__returns_directed_relations() {
+        let gamestate = r###"
+        country={
+            0={
+                name="United Nations of Earth"
+                relations_manager={
+                    relation={
+                        owner=0
+                        country=1
+                        contact=yes
+                        communications=yes
+                        borders=yes
+                        relation_current=120
+                        trust=25.5
+                        research_agreement=yes
+                        defensive_pact=yes
+                    }
+                    relation={
+                        owner=0
+                        country=2
+                        contact=yes
+                        relation_current=-80
+                        is_rival=yes
+                    }
+                }
+            }
+            1={
+                name="Yaanari Imperium"
+                relations_manager={
+                    relation={
+                        owner=1
+                        country=0
+                        contact=yes
+                    }
+                }
+            }
+            2={
+                name="Scyldari Confederacy"
+                relations_manager={ }
+            }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (relations, skipped) = get_relations_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            relations,
+            vec![
+                RelationData {
+                    owner: String::from("United Nations of Earth"),
+                    country: String::from("Yaanari Imperium"),
+                    opinion: 120,
+                    trust: 25.5,
+                    treaties: vec![
+                        String::from("defensive_pact"),
+                        String::from("research_agreement")
+                    ],
+                    rival: false,
+                    borders: true,
+                    contact: true,
+                },
+                RelationData {
+                    owner: String::from("United Nations of Earth"),
+                    country: String::from("Scyldari Confederacy"),
+                    opinion: -80,
+                    rival: true,
+                    contact: true,
+                    ..RelationData::default()
+                },
+                RelationData {
+                    owner: String::from("Yaanari Imperium"),
+                    country: String::from("United Nations of Earth"),
+                    contact: true,
+                    ..RelationData::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_systems_from_gamestate__given_galactic_objects__resolves_hyperlanes_and_owner() {
+        let gamestate = r###"
+        galactic_object={
+            0={
+                coordinate={
+                    x=81.72
+                    y=87.22
+                    origin=4294967295
+                }
+                type=star
+                name="Sol"
+                star_class="sc_g"
+                hyperlane={
+                    {
+                        to=1
+                        length=29
+                    }
+                    {
+                        to=2
+                        length=18
+                    }
+                }
+                starbase=0
+                trade_hub={
+                    collected=28.84798
+                    collected_from={
+                        0 1
+                    }
+                }
+                trade_collection={
+                    targets={
+                        {
+                            target=1
+                            distance=1
+                        }
+                    }
+                }
+                trade_piracy={
+                    throughput=12.5
+                    total=1.5
+                    max=4
+                    active=0
+                    used=0
+                }
+            }
+            1={
+                coordinate={
+                    x=-271.94
+                    y=105.27
+                }
+                type=star
+                name="Flestia"
+                star_class="sc_m"
+                hyperlane={
+                    {
+                        to=0
+                        length=29
+                    }
+                }
+                starbase=4294967295
+            }
+        }
+        starbase_mgr={
+            starbases={
+                0={
+                    level="starbase_level_starport"
+                    owner=0
+                }
+            }
+        }
+        country={
+            0={ name="United Nations of Earth" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (systems, skipped) = get_systems_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            systems,
+            vec![
+                SystemData {
+                    id: 0,
+                    name: String::from("Sol"),
+                    x: 81.72,
+                    y: 87.22,
+                    star_class: String::from("sc_g"),
+                    hyperlanes: vec![1, 2],
+                    owner: Some(String::from("United Nations of Earth")),
+                    trade: TradeHubData {
+                        collected: 28.84798,
+                        collected_from: vec![0, 1],
+                        throughput: 12.5,
+                        piracy: 1.5,
+                    },
+                },
+                SystemData {
+                    id: 1,
+                    name: String::from("Flestia"),
+                    x: -271.94,
+                    y: 105.27,
+                    star_class: String::from("sc_m"),
+                    hyperlanes: vec![0],
+                    owner: None,
+                    trade: TradeHubData::default(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_trade_routes_from_gamestate__given_routes__resolves_owner_and_path() {
+        let gamestate = r###"
+        trade_routes_manager={
+            routes={
+                3={
+                    owner=0
+                    from=1
+                    to=0
+                    delivered=11.5
+                    path={
+                        { id=1 collected=12.5 delivered=12.5 }
+                        { id=0 collected=0 delivered=11.5 }
+                    }
+                }
+            }
+        }
+        country={
+            0={ name="United Nations of Earth" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (routes, skipped) = get_trade_routes_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            routes,
+            vec![TradeRouteData {
+                id: 3,
+                owner: String::from("United Nations of Earth"),
+                from: 1,
+                to: 0,
+                delivered: 11.5,
+                path: vec![1, 0],
+            }]
+        );
+    }
+
+    #[test]
+    fn get_megastructures_from_gamestate__given_owned_and_ruined__resolves_owner() {
+        let gamestate = r###"
+        megastructures={
+            0={
+                type="gateway_ruined"
+                coordinate={ x=21.2148 y=21.2142 origin=18 }
+                planet=4294967295
+            }
+            1={
+                type="dyson_sphere_2"
+                owner=0
+                coordinate={ x=-10.5 y=4.25 origin=0 }
+            }
+            2=none
+        }
+        country={
+            0={ name="United Nations of Earth" }
+        }
+        "###;
+
+        let (_, parse) = root(gamestate).unwrap();
+
+        let (megastructures, skipped) =
+            get_megastructures_from_gamestate(&Node::root(&parse)).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(
+            megastructures,
+            vec![
+                MegastructureData {
+                    id: 0,
+                    megastructure_type: String::from("gateway_ruined"),
+                    owner: None,
+                    system: 18,
+                },
+                MegastructureData {
+                    id: 1,
+                    megastructure_type: String::from("dyson_sphere_2"),
+                    owner: Some(String::from("United Nations of Earth")),
+                    system: 0,
+                },
+            ]
+        );
+    }
 }
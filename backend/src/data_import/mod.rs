@@ -1,5 +1,11 @@
+mod error;
+mod from_val;
 mod import;
 mod key;
+mod query;
 
-pub use import::{DataImport, DataImportResult};
+pub use error::{DataImportError, ImportResult, ValKind};
+pub use from_val::FromVal;
+pub use import::{DataImport, DataImportResult, PartialImport, SkippedEntry};
 pub(self) use key::Key;
+pub use query::Node;
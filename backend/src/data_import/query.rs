@@ -0,0 +1,554 @@
+use chrono::Datelike;
+use clausewitz_parser::Val;
+use data_model::StellarisDate;
+use std::str::FromStr;
+
+use super::{
+    error::{DataImportError, ImportResult, ValKind},
+    from_val::FromVal,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+enum Segment {
+    Key(String),
+    Wildcard,
+    Each(String),
+    Index(String, usize),
+}
+
+fn parse_query(query: &str) -> ImportResult<Vec<Segment>> {
+    query
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| parse_segment(query, segment))
+        .collect()
+}
+
+fn parse_segment(query: &str, segment: &str) -> ImportResult<Segment> {
+    if segment == "*" {
+        return Ok(Segment::Wildcard);
+    }
+    match (segment.find('['), segment.strip_suffix(']')) {
+        (None, _) => Ok(Segment::Key(String::from(segment))),
+        (Some(open), Some(inner)) if open > 0 => {
+            let key = String::from(&segment[..open]);
+            match &inner[open + 1..] {
+                "" => Ok(Segment::Each(key)),
+                index => match index.parse::<usize>() {
+                    Ok(index) => Ok(Segment::Index(key, index)),
+                    Err(_) => Err(invalid_query(query, segment)),
+                },
+            }
+        }
+        _ => Err(invalid_query(query, segment)),
+    }
+}
+
+fn invalid_query(query: &str, segment: &str) -> DataImportError {
+    DataImportError::InvalidValue {
+        path: String::from(query),
+        message: format!("invalid query segment {}", segment),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Node<'a> {
+    path: String,
+    val: &'a Val<'a>,
+}
+
+impl<'a> Node<'a> {
+    pub fn root(val: &'a Val<'a>) -> Self {
+        Node {
+            path: String::new(),
+            val,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn val(&self) -> &'a Val<'a> {
+        self.val
+    }
+
+    pub fn kind(&self) -> ValKind {
+        ValKind::from(self.val)
+    }
+
+    pub fn select(&self, query: &str) -> ImportResult<Vec<Node<'a>>> {
+        let mut nodes = vec![self.clone()];
+        for segment in parse_query(query)? {
+            let mut next = vec![];
+            for node in nodes.iter() {
+                // Children without the key are skipped, anything else is a malformed save.
+                match node.step(&segment) {
+                    Ok(found) => next.extend(found),
+                    Err(DataImportError::MissingKey { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            nodes = next;
+        }
+        Ok(nodes)
+    }
+
+    pub fn get(&self, query: &str) -> ImportResult<Node<'a>> {
+        let mut node = self.clone();
+        for segment in parse_query(query)? {
+            node = match node.step(&segment)?.into_iter().next() {
+                Some(next) => next,
+                None => {
+                    return Err(DataImportError::MissingKey {
+                        path: node.join(&segment_path(&segment)),
+                    })
+                }
+            };
+        }
+        Ok(node)
+    }
+
+    pub fn decode<T: FromVal>(&self, query: &str) -> ImportResult<T> {
+        T::from_val(&self.get(query)?)
+    }
+
+    pub fn decode_or<T: FromVal>(&self, query: &str, default: T) -> ImportResult<T> {
+        match self.get(query) {
+            Ok(node) => T::from_val(&node),
+            Err(DataImportError::MissingKey { .. }) => Ok(default),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn as_f64(&self) -> ImportResult<f64> {
+        match self.val {
+            Val::Decimal(f) => Ok(*f),
+            Val::Integer(i) => Ok(*i as f64),
+            _ => Err(self.unexpected_kind(ValKind::Number)),
+        }
+    }
+
+    pub fn as_i64(&self) -> ImportResult<i64> {
+        match self.val {
+            Val::Integer(i) => Ok(*i),
+            _ => Err(self.unexpected_kind(ValKind::Integer)),
+        }
+    }
+
+    pub fn as_str(&self) -> ImportResult<&'a str> {
+        match self.val {
+            Val::StringLiteral(s) | Val::Identifier(s) => Ok(s),
+            _ => Err(self.unexpected_kind(ValKind::StringLiteral)),
+        }
+    }
+
+    pub fn as_date(&self) -> ImportResult<StellarisDate> {
+        let date = match self.val {
+            Val::Date(date) => StellarisDate::new(date.year(), date.month(), date.day()),
+            _ => StellarisDate::from_str(self.as_str()?),
+        };
+        date.map_err(|e| DataImportError::InvalidValue {
+            path: self.path.clone(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn as_vec(&self) -> ImportResult<Vec<Node<'a>>> {
+        match self.val {
+            Val::Array(vals) | Val::Set(vals) => Ok(self.children(vals)),
+            Val::Dict(pairs) if pairs.is_empty() => Ok(vec![]),
+            _ => Err(self.unexpected_kind(ValKind::Array)),
+        }
+    }
+
+    pub fn as_dict(&self) -> ImportResult<Vec<(&'a str, Node<'a>)>> {
+        match self.val {
+            Val::Dict(pairs) => Ok(pairs
+                .iter()
+                .map(|(key, val)| (*key, self.child(key, val)))
+                .collect()),
+            _ => Err(self.unexpected_kind(ValKind::Dict)),
+        }
+    }
+
+    fn step(&self, segment: &Segment) -> ImportResult<Vec<Node<'a>>> {
+        match segment {
+            Segment::Key(key) => self.values_of(key),
+            Segment::Wildcard => match self.val {
+                Val::Dict(pairs) => Ok(pairs
+                    .iter()
+                    .map(|(key, val)| self.child(key, val))
+                    .collect()),
+                Val::Array(vals) | Val::Set(vals) => Ok(self.children(vals)),
+                _ => Err(self.unexpected_kind_at("*", ValKind::Dict)),
+            },
+            Segment::Each(key) => self.each_of(key),
+            Segment::Index(key, index) => {
+                let mut each = self.each_of(key)?;
+                if *index < each.len() {
+                    Ok(vec![each.swap_remove(*index)])
+                } else {
+                    Err(DataImportError::IndexOutOfBounds {
+                        path: self.join(&format!("{}[{}]", key, index)),
+                        index: *index,
+                        len: each.len(),
+                    })
+                }
+            }
+        }
+    }
+
+    fn values_of(&self, key: &str) -> ImportResult<Vec<Node<'a>>> {
+        match self.val {
+            Val::Dict(pairs) => {
+                let values: Vec<Node<'a>> = pairs
+                    .iter()
+                    .filter(|(k, _)| *k == key)
+                    .map(|(k, val)| self.child(k, val))
+                    .collect();
+                if values.is_empty() {
+                    Err(DataImportError::MissingKey {
+                        path: self.join(key),
+                    })
+                } else {
+                    Ok(values)
+                }
+            }
+            Val::Array(vals) => match key.parse::<usize>() {
+                Ok(index) => match vals.get(index) {
+                    Some(val) => Ok(vec![self.child(key, val)]),
+                    None => Err(DataImportError::IndexOutOfBounds {
+                        path: self.join(key),
+                        index,
+                        len: vals.len(),
+                    }),
+                },
+                Err(_) => Err(self.unexpected_kind_at(key, ValKind::Dict)),
+            },
+            _ => Err(self.unexpected_kind_at(key, ValKind::Dict)),
+        }
+    }
+
+    fn each_of(&self, key: &str) -> ImportResult<Vec<Node<'a>>> {
+        let mut each = vec![];
+        for value in self.values_of(key)? {
+            match value.val {
+                Val::Array(vals) | Val::Set(vals) => each.extend(vals.iter()),
+                Val::Dict(pairs) if pairs.is_empty() => {}
+                val => each.push(val),
+            }
+        }
+        Ok(each
+            .into_iter()
+            .enumerate()
+            .map(|(index, val)| self.child(&format!("{}[{}]", key, index), val))
+            .collect())
+    }
+
+    fn children(&self, vals: &'a [Val<'a>]) -> Vec<Node<'a>> {
+        vals.iter()
+            .enumerate()
+            .map(|(index, val)| self.child(&index.to_string(), val))
+            .collect()
+    }
+
+    fn child(&self, segment: &str, val: &'a Val<'a>) -> Node<'a> {
+        Node {
+            path: self.join(segment),
+            val,
+        }
+    }
+
+    fn join(&self, segment: &str) -> String {
+        if self.path.is_empty() {
+            String::from(segment)
+        } else {
+            format!("{}/{}", self.path, segment)
+        }
+    }
+
+    fn unexpected_kind(&self, expected: ValKind) -> DataImportError {
+        DataImportError::unexpected_kind(expected, self.val).within(&self.path)
+    }
+
+    fn unexpected_kind_at(&self, segment: &str, expected: ValKind) -> DataImportError {
+        DataImportError::unexpected_kind(expected, self.val).within(&self.join(segment))
+    }
+}
+
+fn segment_path(segment: &Segment) -> String {
+    match segment {
+        Segment::Key(key) => key.clone(),
+        Segment::Wildcard => String::from("*"),
+        Segment::Each(key) => format!("{}[]", key),
+        Segment::Index(key, index) => format!("{}[{}]", key, index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clausewitz_parser::root;
+
+    use super::*;
+
+    const GAMESTATE: &str = r###"
+    tech_status={
+        technology="tech_lasers_1"
+        level=1
+        technology="tech_mass_drivers_1"
+        level=1
+        technology="tech_corvettes"
+        level=1
+        physics_queue={
+            {
+                progress=120
+                technology="tech_lasers_2"
+            }
+        }
+    }
+    country={
+        0={
+            name="First Empire"
+            owned_planets={ 3 5 8 }
+            modules={
+                standard_economy_module={
+                    resources={
+                        energy=100
+                        minerals=20.5
+                    }
+                }
+            }
+        }
+        1={
+            name="Second Empire"
+            owned_planets={ }
+            modules={
+                standard_economy_module={
+                    resources={
+                        energy=300
+                    }
+                }
+            }
+        }
+    }
+    date="2200.02.01"
+    "###;
+
+    #[test]
+    fn select__given_wildcard__returns_value_for_each_child() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let energy = Node::root(&gamestate)
+            .select("country/*/modules/standard_economy_module/resources/energy")
+            .unwrap();
+
+        assert_eq!(
+            energy
+                .iter()
+                .map(|node| node.as_f64().unwrap())
+                .collect::<Vec<f64>>(),
+            vec![100f64, 300f64]
+        );
+        assert_eq!(
+            energy[1].path(),
+            "country/1/modules/standard_economy_module/resources/energy"
+        );
+    }
+
+    #[test]
+    fn select__given_missing_keys__skips_children_without_key() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let minerals = Node::root(&gamestate)
+            .select("country/*/modules/standard_economy_module/resources/minerals")
+            .unwrap();
+
+        assert_eq!(minerals.len(), 1);
+        assert_eq!(minerals[0].as_f64().unwrap(), 20.5);
+    }
+
+    #[test]
+    fn select__given_repeated_keys__returns_every_value() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let techs = Node::root(&gamestate)
+            .select("tech_status/technology[]")
+            .unwrap();
+
+        assert_eq!(
+            techs
+                .iter()
+                .map(|node| node.as_str().unwrap())
+                .collect::<Vec<&str>>(),
+            vec!["tech_lasers_1", "tech_mass_drivers_1", "tech_corvettes"]
+        );
+        assert_eq!(techs[2].path(), "tech_status/technology[2]");
+    }
+
+    #[test]
+    fn select__given_child_of_unexpected_kind__returns_error() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let error = Node::root(&gamestate)
+            .select("tech_status/technology[]/level")
+            .unwrap_err();
+
+        assert_eq!(error.path(), "tech_status/technology[0]/level");
+    }
+
+    #[test]
+    fn get__given_index__returns_indexed_value() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+        let root = Node::root(&gamestate);
+
+        assert_eq!(
+            root.get("tech_status/technology[1]")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "tech_mass_drivers_1"
+        );
+        assert_eq!(
+            root.get("country/0/owned_planets[2]")
+                .unwrap()
+                .as_i64()
+                .unwrap(),
+            8
+        );
+        assert_eq!(
+            root.get("tech_status/physics_queue[0]/technology")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "tech_lasers_2"
+        );
+    }
+
+    #[test]
+    fn get__given_out_of_bounds_index__returns_error() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let error = Node::root(&gamestate)
+            .get("country/0/owned_planets[3]")
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::IndexOutOfBounds {
+                path: String::from("country/0/owned_planets[3]"),
+                index: 3,
+                len: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn get__given_missing_key__returns_error_with_path() {
+        let (_, dict) = root("budget={ current_month={ } }").unwrap();
+
+        let error = Node::root(&dict)
+            .get("budget/current_month/income")
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::MissingKey {
+                path: String::from("budget/current_month/income")
+            }
+        );
+    }
+
+    #[test]
+    fn get__given_wrong_kind__returns_expected_and_actual_kind() {
+        let (_, dict) = root("budget=\"none\"").unwrap();
+
+        let error = Node::root(&dict).get("budget/current_month").unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::UnexpectedKind {
+                path: String::from("budget/current_month"),
+                expected: ValKind::Dict,
+                actual: ValKind::StringLiteral,
+            }
+        );
+    }
+
+    #[test]
+    fn as_vec__given_set_or_empty_block__returns_elements() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+        let root = Node::root(&gamestate);
+
+        let planets = root
+            .get("country/0/owned_planets")
+            .unwrap()
+            .as_vec()
+            .unwrap();
+        let empty = root
+            .get("country/1/owned_planets")
+            .unwrap()
+            .as_vec()
+            .unwrap();
+
+        assert_eq!(
+            planets
+                .iter()
+                .map(|node| node.as_i64().unwrap())
+                .collect::<Vec<i64>>(),
+            vec![3, 5, 8]
+        );
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn as_str__given_identifier__returns_identifier() {
+        let (_, leader) = root("location={ type=planet id=3 }").unwrap();
+
+        let assignment = Node::root(&leader).get("location/type").unwrap();
+
+        assert_eq!(assignment.as_str(), Ok("planet"));
+    }
+
+    #[test]
+    fn as_str__given_number__returns_unexpected_kind_with_path() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let error = Node::root(&gamestate)
+            .get("country/0/modules/standard_economy_module/resources/energy")
+            .unwrap()
+            .as_str()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            DataImportError::UnexpectedKind {
+                path: String::from("country/0/modules/standard_economy_module/resources/energy"),
+                expected: ValKind::StringLiteral,
+                actual: ValKind::Integer,
+            }
+        );
+    }
+
+    #[test]
+    fn as_date__given_date__returns_stellaris_date() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        let date = Node::root(&gamestate)
+            .get("date")
+            .unwrap()
+            .as_date()
+            .unwrap();
+
+        assert_eq!(date, StellarisDate::new(2200, 2, 1).unwrap());
+    }
+
+    #[test]
+    fn select__given_malformed_query__returns_error() {
+        let (_, gamestate) = root(GAMESTATE).unwrap();
+
+        assert!(Node::root(&gamestate).select("country/[0]").is_err());
+        assert!(Node::root(&gamestate).select("country/planets[a]").is_err());
+        assert!(Node::root(&gamestate).get("country/planets[0").is_err());
+    }
+}
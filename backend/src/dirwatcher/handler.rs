@@ -34,7 +34,7 @@ impl DirectoryEventHandler {
             let parse_result = DataImport::from_file(&path);
             match parse_result {
                 Ok(data_point) => custodian_message_sender
-                    .send(CustodianMsg::Data(data_point))
+                    .send(CustodianMsg::Data(Box::new(data_point)))
                     .unwrap(),
                 Err(e) => log::error!("{}", e),
             };
@@ -60,7 +60,9 @@ impl DirectoryEventHandler {
                     }) => {
                         match DataImport::from_file(&path) {
                             Ok(data) => {
-                                match custodian_message_sender.send(CustodianMsg::Data(data)) {
+                                match custodian_message_sender
+                                    .send(CustodianMsg::Data(Box::new(data)))
+                                {
                                     Ok(_) => {}
                                     Err(_) => {
                                         log::warn!(
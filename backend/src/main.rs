@@ -1,11 +1,23 @@
 use actix_cors::Cors;
 use actix_web::{middleware, web::Data, App, HttpResponse, HttpServer, Responder};
 use backend::{
-    api::empires, campaign_select::selector::CampaignSelector, dirwatcher::DirectoryEventHandler,
+    api::{
+        campaign_diplomacy, campaign_leaderboard, campaign_market, campaign_relations,
+        campaign_wars, empire_budget, empire_demographics, empire_identity, empire_leaders,
+        empire_megastructures, empire_military, empire_planets, empire_resources,
+        empire_technologies, empire_trade, empires, galaxy, updates,
+    },
+    broadcaster::Broadcaster,
+    campaign_select::selector::CampaignSelector,
+    dirwatcher::DirectoryEventHandler,
 };
+use data_core::{DataCore, DuplicatePolicy};
 use data_model::ModelCustodian;
 use listenfd::ListenFd;
-use std::{panic, path::PathBuf, process::exit};
+use std::{panic, path::PathBuf, process::exit, str::FromStr};
+
+const DATABASE_NAME: &str = "stellarust.db";
+const DUPLICATE_POLICY_VAR: &str = "STELLARUST_DUPLICATE_POLICY";
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -27,14 +39,60 @@ async fn main() -> std::io::Result<()> {
 
     let (receiver, _dir_watcher) = DirectoryEventHandler::create(&campaign_path);
 
-    let custodian_data = Data::new(ModelCustodian::create(receiver));
+    let data_core = match DataCore::create(&std::env::current_dir()?, &DATABASE_NAME).await {
+        Ok(data_core) => data_core,
+        Err(error) => {
+            println!("{:?}", error);
+            exit(-1)
+        }
+    };
+
+    let policy = match std::env::var(DUPLICATE_POLICY_VAR) {
+        Ok(policy) => match DuplicatePolicy::from_str(&policy) {
+            Ok(policy) => policy,
+            Err(error) => {
+                println!("{}: {:?}", DUPLICATE_POLICY_VAR, error);
+                exit(-1)
+            }
+        },
+        Err(_) => DuplicatePolicy::default(),
+    };
+
+    let custodian = match ModelCustodian::create(receiver, data_core, policy).await {
+        Ok(custodian) => custodian,
+        Err(error) => {
+            println!("{:?}", error);
+            exit(-1)
+        }
+    };
+
+    let broadcaster = Broadcaster::create(custodian.subscribe());
+    let custodian_data = Data::new(custodian);
 
     let mut server = HttpServer::new(move || {
         App::new()
             .wrap(middleware::Logger::default())
             .wrap(Cors::default().allow_any_origin())
             .app_data(custodian_data.clone())
+            .app_data(broadcaster.clone())
             .service(empires)
+            .service(empire_resources)
+            .service(empire_budget)
+            .service(empire_planets)
+            .service(empire_demographics)
+            .service(empire_identity)
+            .service(empire_leaders)
+            .service(empire_military)
+            .service(empire_technologies)
+            .service(empire_trade)
+            .service(empire_megastructures)
+            .service(campaign_wars)
+            .service(campaign_diplomacy)
+            .service(campaign_relations)
+            .service(campaign_leaderboard)
+            .service(campaign_market)
+            .service(galaxy)
+            .service(updates)
     });
 
     server = if let Some(listener) = ListenFd::from_env().take_tcp_listener(0)? {
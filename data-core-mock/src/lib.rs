@@ -1,4 +1,107 @@
-use data_core::DataCoreBackend;
+use std::sync::Mutex;
 
-pub struct MockDataCore {}
-impl DataCoreBackend for MockDataCore {}
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use data_core::{DataCoreBackend, DuplicatePolicy, EmpireData, ModelDataPoint};
+
+#[derive(Default)]
+pub struct MockDataCore {
+    snapshots: Mutex<Vec<ModelDataPoint>>,
+    current_campaign: Mutex<Option<String>>,
+    fail_stores: bool,
+}
+
+impl MockDataCore {
+    pub fn new() -> Self {
+        MockDataCore::default()
+    }
+
+    pub fn with_snapshots(snapshots: Vec<ModelDataPoint>) -> Self {
+        MockDataCore {
+            current_campaign: Mutex::new(
+                snapshots
+                    .last()
+                    .map(|snapshot| snapshot.campaign_name.clone()),
+            ),
+            snapshots: Mutex::new(snapshots),
+            fail_stores: false,
+        }
+    }
+
+    pub fn with_failing_stores() -> Self {
+        MockDataCore {
+            fail_stores: true,
+            ..MockDataCore::default()
+        }
+    }
+}
+
+#[async_trait]
+impl DataCoreBackend for MockDataCore {
+    async fn store_snapshot(
+        &self,
+        data_point: &ModelDataPoint,
+        policy: DuplicatePolicy,
+    ) -> Result<()> {
+        if self.fail_stores {
+            return Err(anyhow!("Could not store snapshot"));
+        }
+        let mut snapshots = self.snapshots.lock().unwrap();
+        let existing = snapshots.iter().position(|snapshot| {
+            snapshot.campaign_name == data_point.campaign_name && snapshot.date == data_point.date
+        });
+        match (existing, policy) {
+            (Some(_), DuplicatePolicy::KeepFirst) => return Ok(()),
+            (Some(index), DuplicatePolicy::Replace) => snapshots[index] = data_point.clone(),
+            (None, _) => {
+                let index = snapshots.partition_point(|snapshot| snapshot.date <= data_point.date);
+                snapshots.insert(index, data_point.clone())
+            }
+        }
+        *self.current_campaign.lock().unwrap() = Some(data_point.campaign_name.clone());
+        Ok(())
+    }
+
+    async fn get_snapshots(&self) -> Result<Vec<ModelDataPoint>> {
+        Ok(self.snapshots.lock().unwrap().clone())
+    }
+
+    async fn get_campaign_names(&self) -> Result<Vec<String>> {
+        let mut names: Vec<String> = vec![];
+        for snapshot in self.snapshots.lock().unwrap().iter() {
+            if !names.contains(&snapshot.campaign_name) {
+                names.push(snapshot.campaign_name.clone());
+            }
+        }
+        Ok(names)
+    }
+
+    async fn get_current_campaign_name(&self) -> Result<Option<String>> {
+        Ok(self.current_campaign.lock().unwrap().clone())
+    }
+
+    async fn get_empire_series(
+        &self,
+        campaign_name: &str,
+        empire_name: &str,
+    ) -> Result<Vec<EmpireData>> {
+        Ok(self
+            .snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|snapshot| snapshot.campaign_name == campaign_name)
+            .flat_map(|snapshot| snapshot.empires.iter())
+            .filter(|empire| empire.name == empire_name)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_campaign(&self, campaign_name: &str) -> Result<()> {
+        self.snapshots
+            .lock()
+            .unwrap()
+            .retain(|snapshot| snapshot.campaign_name != campaign_name);
+        Ok(())
+    }
+}
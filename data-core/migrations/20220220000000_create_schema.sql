@@ -0,0 +1,36 @@
+CREATE TABLE IF NOT EXISTS campaigns (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE IF NOT EXISTS snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    campaign_id INTEGER NOT NULL REFERENCES campaigns (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS empires (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS resources (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    resource TEXT NOT NULL,
+    amount REAL NOT NULL,
+    PRIMARY KEY (empire_id, resource)
+);
+
+CREATE TABLE IF NOT EXISTS budget_lines (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    month TEXT NOT NULL,
+    component TEXT NOT NULL,
+    resource TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    contributor TEXT NOT NULL,
+    amount REAL NOT NULL,
+    PRIMARY KEY (empire_id, month, component, resource, position)
+);
+
+CREATE INDEX IF NOT EXISTS snapshots_campaign_id ON snapshots (campaign_id);
+CREATE INDEX IF NOT EXISTS empires_snapshot_id ON empires (snapshot_id);
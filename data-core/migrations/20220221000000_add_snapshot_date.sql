@@ -0,0 +1,4 @@
+ALTER TABLE snapshots ADD COLUMN date TEXT NOT NULL DEFAULT '';
+
+CREATE UNIQUE INDEX IF NOT EXISTS snapshots_campaign_id_date ON snapshots (campaign_id, date)
+WHERE date <> '';
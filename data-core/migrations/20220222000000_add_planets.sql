@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS planets (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    planet_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    class TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    owner INTEGER NOT NULL,
+    controller INTEGER NOT NULL,
+    pops INTEGER NOT NULL,
+    stability REAL NOT NULL,
+    amenities REAL NOT NULL,
+    housing REAL NOT NULL,
+    crime REAL NOT NULL,
+    PRIMARY KEY (empire_id, planet_id)
+);
+
+CREATE TABLE IF NOT EXISTS planet_districts (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    planet_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    district TEXT NOT NULL,
+    PRIMARY KEY (empire_id, planet_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS planet_buildings (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    planet_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    building TEXT NOT NULL,
+    PRIMARY KEY (empire_id, planet_id, position)
+);
@@ -0,0 +1,9 @@
+ALTER TABLE empires ADD COLUMN happiness REAL NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS demographics (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    category TEXT NOT NULL,
+    name TEXT NOT NULL,
+    pops INTEGER NOT NULL,
+    PRIMARY KEY (empire_id, category, name)
+);
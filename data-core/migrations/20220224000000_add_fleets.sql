@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS fleets (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    fleet_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    ships INTEGER NOT NULL,
+    military_power REAL NOT NULL,
+    system INTEGER NOT NULL,
+    PRIMARY KEY (empire_id, fleet_id)
+);
+
+CREATE TABLE IF NOT EXISTS fleet_classes (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    fleet_id INTEGER NOT NULL,
+    class TEXT NOT NULL,
+    ships INTEGER NOT NULL,
+    PRIMARY KEY (empire_id, fleet_id, class)
+);
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS technologies (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    technology TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    PRIMARY KEY (empire_id, technology)
+);
+
+CREATE TABLE IF NOT EXISTS research (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    area TEXT NOT NULL,
+    technology TEXT NOT NULL,
+    progress REAL NOT NULL,
+    PRIMARY KEY (empire_id, area)
+);
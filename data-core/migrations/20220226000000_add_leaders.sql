@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS leaders (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    leader_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    class TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    age INTEGER NOT NULL,
+    assignment TEXT NOT NULL,
+    location INTEGER NOT NULL,
+    owner INTEGER NOT NULL,
+    PRIMARY KEY (empire_id, leader_id)
+);
+
+CREATE TABLE IF NOT EXISTS leader_traits (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    leader_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (empire_id, leader_id, position)
+);
@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS wars (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    war_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    attacker_war_goal TEXT NOT NULL,
+    defender_war_goal TEXT,
+    attacker_exhaustion REAL NOT NULL,
+    defender_exhaustion REAL NOT NULL,
+    PRIMARY KEY (snapshot_id, war_id)
+);
+
+CREATE TABLE IF NOT EXISTS war_participants (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    war_id INTEGER NOT NULL,
+    side TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    country TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, war_id, side, position)
+);
+
+CREATE TABLE IF NOT EXISTS truces (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    truce_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    start_date TEXT NOT NULL,
+    truce_type TEXT,
+    PRIMARY KEY (snapshot_id, truce_id)
+);
+
+CREATE TABLE IF NOT EXISTS truce_parties (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    truce_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    country TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, truce_id, position)
+);
@@ -0,0 +1,27 @@
+CREATE TABLE IF NOT EXISTS federations (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    federation_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    federation_type TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    cohesion REAL NOT NULL,
+    leader TEXT,
+    PRIMARY KEY (snapshot_id, federation_id)
+);
+
+CREATE TABLE IF NOT EXISTS federation_members (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    federation_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    country TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, federation_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS galactic_community (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    category TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, category, position)
+);
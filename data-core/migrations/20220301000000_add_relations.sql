@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS relations (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    owner TEXT NOT NULL,
+    country TEXT NOT NULL,
+    opinion INTEGER NOT NULL,
+    trust REAL NOT NULL,
+    rival BOOLEAN NOT NULL,
+    borders BOOLEAN NOT NULL,
+    contact BOOLEAN NOT NULL,
+    PRIMARY KEY (snapshot_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS relation_treaties (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    relation INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    treaty TEXT NOT NULL,
+    PRIMARY KEY (snapshot_id, relation, position)
+);
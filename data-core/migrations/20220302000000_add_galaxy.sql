@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS systems (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    system_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    star_class TEXT NOT NULL,
+    owner TEXT,
+    PRIMARY KEY (snapshot_id, system_id)
+);
+
+CREATE TABLE IF NOT EXISTS hyperlanes (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    system_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    destination INTEGER NOT NULL,
+    PRIMARY KEY (snapshot_id, system_id, position)
+);
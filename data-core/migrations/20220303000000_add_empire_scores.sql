@@ -0,0 +1,8 @@
+ALTER TABLE empires ADD COLUMN military_power REAL NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN economy_power REAL NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN tech_power REAL NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN victory_score REAL NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN victory_rank INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN empire_size REAL NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN fleet_size INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE empires ADD COLUMN starbase_capacity INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS empire_identities (
+    empire_id INTEGER NOT NULL PRIMARY KEY REFERENCES empires (id) ON DELETE CASCADE,
+    government TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    origin TEXT NOT NULL,
+    ruler INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS empire_identity_names (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    category TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (empire_id, category, position)
+);
+
+CREATE TABLE IF NOT EXISTS policies (
+    empire_id INTEGER NOT NULL REFERENCES empires (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    policy TEXT NOT NULL,
+    selected TEXT NOT NULL,
+    date TEXT,
+    PRIMARY KEY (empire_id, position)
+);
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS market_prices (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    resource TEXT NOT NULL,
+    fluctuation REAL NOT NULL,
+    price REAL NOT NULL,
+    PRIMARY KEY (snapshot_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS market_trades (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    country TEXT NOT NULL,
+    resource TEXT NOT NULL,
+    bought REAL NOT NULL,
+    sold REAL NOT NULL,
+    PRIMARY KEY (snapshot_id, position)
+);
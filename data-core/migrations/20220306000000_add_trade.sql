@@ -0,0 +1,31 @@
+ALTER TABLE empires ADD COLUMN trade_value REAL NOT NULL DEFAULT 0;
+ALTER TABLE systems ADD COLUMN trade_collected REAL NOT NULL DEFAULT 0;
+ALTER TABLE systems ADD COLUMN trade_throughput REAL NOT NULL DEFAULT 0;
+ALTER TABLE systems ADD COLUMN piracy REAL NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS trade_collections (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    system_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    source INTEGER NOT NULL,
+    PRIMARY KEY (snapshot_id, system_id, position)
+);
+
+CREATE TABLE IF NOT EXISTS trade_routes (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    route_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    owner TEXT NOT NULL,
+    from_system INTEGER NOT NULL,
+    to_system INTEGER NOT NULL,
+    delivered REAL NOT NULL,
+    PRIMARY KEY (snapshot_id, route_id)
+);
+
+CREATE TABLE IF NOT EXISTS trade_route_paths (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    route_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    system_id INTEGER NOT NULL,
+    PRIMARY KEY (snapshot_id, route_id, position)
+);
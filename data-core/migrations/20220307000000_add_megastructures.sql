@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS megastructures (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    megastructure_id INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    megastructure_type TEXT NOT NULL,
+    owner TEXT,
+    system INTEGER NOT NULL,
+    PRIMARY KEY (snapshot_id, megastructure_id)
+);
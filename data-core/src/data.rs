@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use stellarust::dto::ResourceClass;
+
+use crate::date::StellarisDate;
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Resources {
+    pub energy: f64,
+    pub minerals: f64,
+    pub food: f64,
+
+    pub physics_research: f64,
+    pub society_research: f64,
+    pub engineering_research: f64,
+
+    pub influence: f64,
+    pub unity: f64,
+    pub consumer_goods: f64,
+
+    pub alloys: f64,
+
+    pub volatile_motes: f64,
+    pub exotic_gases: f64,
+    pub rare_crystals: f64,
+
+    pub sr_living_metal: f64,
+    pub sr_zro: f64,
+    pub sr_dark_matter: f64,
+}
+
+impl Resources {
+    pub fn get(&self, class: &ResourceClass) -> f64 {
+        match class {
+            ResourceClass::Energy => self.energy,
+            ResourceClass::Minerals => self.minerals,
+            ResourceClass::Food => self.food,
+            ResourceClass::Physics => self.physics_research,
+            ResourceClass::Society => self.society_research,
+            ResourceClass::Engineering => self.engineering_research,
+            ResourceClass::Influence => self.influence,
+            ResourceClass::Unity => self.unity,
+            ResourceClass::ConsumerGoods => self.consumer_goods,
+            ResourceClass::Alloys => self.alloys,
+            ResourceClass::Motes => self.volatile_motes,
+            ResourceClass::Gasses => self.exotic_gases,
+            ResourceClass::Crystals => self.rare_crystals,
+            ResourceClass::LivingMetal => self.sr_living_metal,
+            ResourceClass::Zro => self.sr_zro,
+            ResourceClass::DarkMatter => self.sr_dark_matter,
+        }
+    }
+
+    pub fn set(&mut self, class: &ResourceClass, amount: f64) {
+        let field = match class {
+            ResourceClass::Energy => &mut self.energy,
+            ResourceClass::Minerals => &mut self.minerals,
+            ResourceClass::Food => &mut self.food,
+            ResourceClass::Physics => &mut self.physics_research,
+            ResourceClass::Society => &mut self.society_research,
+            ResourceClass::Engineering => &mut self.engineering_research,
+            ResourceClass::Influence => &mut self.influence,
+            ResourceClass::Unity => &mut self.unity,
+            ResourceClass::ConsumerGoods => &mut self.consumer_goods,
+            ResourceClass::Alloys => &mut self.alloys,
+            ResourceClass::Motes => &mut self.volatile_motes,
+            ResourceClass::Gasses => &mut self.exotic_gases,
+            ResourceClass::Crystals => &mut self.rare_crystals,
+            ResourceClass::LivingMetal => &mut self.sr_living_metal,
+            ResourceClass::Zro => &mut self.sr_zro,
+            ResourceClass::DarkMatter => &mut self.sr_dark_matter,
+        };
+        *field = amount;
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Budget {
+    pub income: HashMap<ResourceClass, Vec<(String, f64)>>,
+    pub expense: HashMap<ResourceClass, Vec<(String, f64)>>,
+    pub balance: HashMap<ResourceClass, Vec<(String, f64)>>,
+
+    pub income_last_month: HashMap<ResourceClass, Vec<(String, f64)>>,
+    pub expense_last_month: HashMap<ResourceClass, Vec<(String, f64)>>,
+    pub balance_last_month: HashMap<ResourceClass, Vec<(String, f64)>>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PlanetData {
+    pub id: i64,
+    pub name: String,
+    pub class: String,
+    pub size: i64,
+    pub owner: i64,
+    pub controller: i64,
+    pub pops: i64,
+    pub districts: Vec<String>,
+    pub buildings: Vec<String>,
+    pub stability: f64,
+    pub amenities: f64,
+    pub housing: f64,
+    pub crime: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Demographics {
+    pub species: HashMap<String, i64>,
+    pub jobs: HashMap<String, i64>,
+    pub strata: HashMap<String, i64>,
+    pub ethics: HashMap<String, i64>,
+    pub happiness: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct FleetData {
+    pub id: i64,
+    pub name: String,
+    pub ships: i64,
+    pub classes: HashMap<String, i64>,
+    pub military_power: f64,
+    pub system: i64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ResearchData {
+    pub technology: String,
+    pub progress: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TechStatus {
+    pub researched: HashMap<String, i64>,
+    pub physics: Option<ResearchData>,
+    pub society: Option<ResearchData>,
+    pub engineering: Option<ResearchData>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct LeaderData {
+    pub id: i64,
+    pub name: String,
+    pub class: String,
+    pub level: i64,
+    pub traits: Vec<String>,
+    pub age: i64,
+    pub assignment: String,
+    pub location: i64,
+    pub owner: i64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct PolicyData {
+    pub policy: String,
+    pub selected: String,
+    pub date: Option<StellarisDate>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct EmpireIdentity {
+    pub government: String,
+    pub authority: String,
+    pub civics: Vec<String>,
+    pub ethics: Vec<String>,
+    pub origin: String,
+    pub policies: Vec<PolicyData>,
+    pub ruler: Option<i64>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct EmpireData {
+    pub name: String,
+    pub budget: Budget,
+    pub resources: Resources,
+    pub planets: Vec<PlanetData>,
+    pub demographics: Demographics,
+    pub fleets: Vec<FleetData>,
+    pub tech: TechStatus,
+    pub leaders: Vec<LeaderData>,
+    pub military_power: f64,
+    pub economy_power: f64,
+    pub tech_power: f64,
+    pub victory_score: f64,
+    pub victory_rank: i64,
+    pub empire_size: f64,
+    pub fleet_size: i64,
+    pub starbase_capacity: i64,
+    pub identity: EmpireIdentity,
+    pub trade_value: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct WarData {
+    pub id: i64,
+    pub name: String,
+    pub start_date: StellarisDate,
+    pub attackers: Vec<String>,
+    pub defenders: Vec<String>,
+    pub attacker_war_goal: String,
+    pub defender_war_goal: Option<String>,
+    pub attacker_exhaustion: f64,
+    pub defender_exhaustion: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct FederationData {
+    pub id: i64,
+    pub name: String,
+    pub federation_type: String,
+    pub level: i64,
+    pub cohesion: f64,
+    pub leader: Option<String>,
+    pub members: Vec<String>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct GalacticCommunity {
+    pub members: Vec<String>,
+    pub council: Vec<String>,
+    pub resolutions: Vec<String>,
+    pub voting: Option<String>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct RelationData {
+    pub owner: String,
+    pub country: String,
+    pub opinion: i64,
+    pub trust: f64,
+    pub treaties: Vec<String>,
+    pub rival: bool,
+    pub borders: bool,
+    pub contact: bool,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TradeHubData {
+    pub collected: f64,
+    pub collected_from: Vec<i64>,
+    pub throughput: f64,
+    pub piracy: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct SystemData {
+    pub id: i64,
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    pub star_class: String,
+    pub hyperlanes: Vec<i64>,
+    pub owner: Option<String>,
+    pub trade: TradeHubData,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct TradeRouteData {
+    pub id: i64,
+    pub owner: String,
+    pub from: i64,
+    pub to: i64,
+    pub delivered: f64,
+    pub path: Vec<i64>,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MegastructureData {
+    pub id: i64,
+    pub megastructure_type: String,
+    pub owner: Option<String>,
+    pub system: i64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TruceData {
+    pub id: i64,
+    pub name: String,
+    pub start_date: StellarisDate,
+    pub truce_type: Option<String>,
+    pub countries: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MarketResourceData {
+    pub resource: ResourceClass,
+    pub fluctuation: f64,
+    pub price: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MarketTradeData {
+    pub country: String,
+    pub resource: ResourceClass,
+    pub bought: f64,
+    pub sold: f64,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct MarketData {
+    pub resources: Vec<MarketResourceData>,
+    pub trades: Vec<MarketTradeData>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ModelDataPoint {
+    pub campaign_name: String,
+    pub date: StellarisDate,
+    pub empires: Vec<EmpireData>,
+    pub wars: Vec<WarData>,
+    pub truces: Vec<TruceData>,
+    pub federations: Vec<FederationData>,
+    pub galactic_community: GalacticCommunity,
+    pub relations: Vec<RelationData>,
+    pub systems: Vec<SystemData>,
+    pub market: MarketData,
+    pub trade_routes: Vec<TradeRouteData>,
+    pub megastructures: Vec<MegastructureData>,
+}
@@ -1,16 +1,158 @@
 use std::{
+    collections::HashMap,
     path::{Path, PathBuf},
-    process::Command,
+    str::FromStr,
 };
 
 use anyhow::Result;
-use sqlx::SqlitePool;
+use async_trait::async_trait;
+use sqlx::{
+    query::QueryAs,
+    sqlite::{SqliteArguments, SqliteConnectOptions},
+    Sqlite, SqlitePool, Transaction,
+};
+use stellarust::dto::{BudgetComponent, ResourceClass};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumString};
+
+use crate::{
+    data::{
+        Budget, Demographics, EmpireData, EmpireIdentity, FederationData, FleetData,
+        GalacticCommunity, LeaderData, MarketData, MarketResourceData, MarketTradeData,
+        MegastructureData, ModelDataPoint, PlanetData, PolicyData, RelationData, ResearchData,
+        Resources, SystemData, TechStatus, TradeHubData, TradeRouteData, TruceData, WarData,
+    },
+    date::StellarisDate,
+};
+
+const CURRENT_MONTH: &str = "current_month";
+const LAST_MONTH: &str = "last_month";
+const SPECIES: &str = "species";
+const JOB: &str = "job";
+const STRATUM: &str = "stratum";
+const ETHIC: &str = "ethic";
+const PHYSICS: &str = "physics";
+const SOCIETY: &str = "society";
+const ENGINEERING: &str = "engineering";
+const ATTACKER: &str = "attacker";
+const DEFENDER: &str = "defender";
+const MEMBER: &str = "member";
+const COUNCIL: &str = "council";
+const RESOLUTION: &str = "resolution";
+const VOTING: &str = "voting";
+const CIVIC: &str = "civic";
+
+type BudgetMap = HashMap<ResourceClass, Vec<(String, f64)>>;
+type EmpireRow = (
+    i64,
+    String,
+    f64,
+    f64,
+    f64,
+    f64,
+    f64,
+    i64,
+    f64,
+    i64,
+    i64,
+    f64,
+);
+type PlanetRow = (
+    i64,
+    i64,
+    String,
+    String,
+    i64,
+    i64,
+    i64,
+    i64,
+    f64,
+    f64,
+    f64,
+    f64,
+);
+type LeaderRow = (i64, i64, String, String, i64, i64, String, i64, i64);
+type WarRow = (i64, String, String, String, Option<String>, f64, f64);
+type TruceRow = (i64, String, String, Option<String>);
+type FederationRow = (i64, String, String, i64, f64, Option<String>);
+type RelationRow = (i64, String, String, i64, f64, bool, bool, bool);
+type IdentityRow = (i64, String, String, String, Option<i64>);
+type SystemRow = (i64, String, f64, f64, String, Option<String>, f64, f64, f64);
+type TradeRouteRow = (i64, String, i64, i64, f64);
+type MegastructureRow = (i64, String, Option<String>, i64);
+
+#[derive(Debug, Default, Display, EnumString, PartialEq, Eq, Clone, Copy)]
+pub enum DuplicatePolicy {
+    #[default]
+    Replace,
+    KeepFirst,
+}
+
+#[async_trait]
+pub trait DataCoreBackend: Send + Sync + 'static {
+    async fn store_snapshot(
+        &self,
+        data_point: &ModelDataPoint,
+        policy: DuplicatePolicy,
+    ) -> Result<()>;
+    async fn get_snapshots(&self) -> Result<Vec<ModelDataPoint>>;
+    async fn get_campaign_names(&self) -> Result<Vec<String>>;
+    async fn get_current_campaign_name(&self) -> Result<Option<String>>;
+    async fn get_empire_series(
+        &self,
+        campaign_name: &str,
+        empire_name: &str,
+    ) -> Result<Vec<EmpireData>>;
+    async fn delete_campaign(&self, campaign_name: &str) -> Result<()>;
+}
+
+/// The empires whose child rows are loaded together, one query per table.
+#[derive(Clone, Copy)]
+enum EmpireFilter<'a> {
+    Snapshot(i64),
+    Series {
+        campaign_name: &'a str,
+        empire_name: &'a str,
+    },
+}
+
+impl<'a> EmpireFilter<'a> {
+    fn condition(&self, column: &str) -> String {
+        match self {
+            EmpireFilter::Snapshot(_) => format!(
+                "{} IN (SELECT id FROM empires WHERE snapshot_id = ?)",
+                column
+            ),
+            EmpireFilter::Series { .. } => format!(
+                "{} IN (SELECT empires.id FROM empires \
+                 INNER JOIN snapshots ON snapshots.id = empires.snapshot_id \
+                 INNER JOIN campaigns ON campaigns.id = snapshots.campaign_id \
+                 WHERE campaigns.name = ? AND empires.name = ?)",
+                column
+            ),
+        }
+    }
 
-pub trait DataCoreBackend {}
-impl DataCoreBackend for DataCore {}
+    fn bind<'q, O>(
+        self,
+        query: QueryAs<'q, Sqlite, O, SqliteArguments<'q>>,
+    ) -> QueryAs<'q, Sqlite, O, SqliteArguments<'q>>
+    where
+        'a: 'q,
+    {
+        match self {
+            EmpireFilter::Snapshot(snapshot_id) => query.bind(snapshot_id),
+            EmpireFilter::Series {
+                campaign_name,
+                empire_name,
+            } => query.bind(campaign_name).bind(empire_name),
+        }
+    }
+}
 
+#[derive(Clone)]
 pub struct DataCore {
-    _pool: SqlitePool,
+    pool: SqlitePool,
 }
 
 impl DataCore {
@@ -23,22 +165,1651 @@ impl DataCore {
     }
 
     async fn _create(path: &Path, name: &str) -> Result<Self> {
-        let database_url = format!(
-            "sqlite:{}",
-            PathBuf::from_iter(vec![path.to_str().unwrap(), name])
-                .to_str()
-                .unwrap()
-        );
-
-        Command::new("sqlx")
-            .args(&["database", "create", "--database-url", &database_url])
-            .output()
-            .unwrap();
+        let options = SqliteConnectOptions::new()
+            .filename(PathBuf::from_iter(vec![path, Path::new(name)]))
+            .create_if_missing(true)
+            .foreign_keys(true);
 
         let me = DataCore {
-            _pool: SqlitePool::connect(&database_url).await?,
+            pool: SqlitePool::connect_with(options).await?,
         };
 
+        sqlx::migrate!("./migrations").run(&me.pool).await?;
+
         Ok(me)
     }
+
+    async fn get_market(&self, snapshot_id: i64) -> Result<MarketData> {
+        let prices: Vec<(String, f64, f64)> = sqlx::query_as(
+            "SELECT resource, fluctuation, price FROM market_prices \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let trades: Vec<(String, String, f64, f64)> = sqlx::query_as(
+            "SELECT country, resource, bought, sold FROM market_trades \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(MarketData {
+            resources: prices
+                .into_iter()
+                .map(|(resource, fluctuation, price)| {
+                    Ok(MarketResourceData {
+                        resource: ResourceClass::from_str(&resource)?,
+                        fluctuation,
+                        price,
+                    })
+                })
+                .collect::<Result<_>>()?,
+            trades: trades
+                .into_iter()
+                .map(|(country, resource, bought, sold)| {
+                    Ok(MarketTradeData {
+                        country,
+                        resource: ResourceClass::from_str(&resource)?,
+                        bought,
+                        sold,
+                    })
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    async fn get_wars(&self, snapshot_id: i64) -> Result<Vec<WarData>> {
+        let rows: Vec<WarRow> = sqlx::query_as(
+            "SELECT war_id, name, start_date, attacker_war_goal, defender_war_goal, \
+             attacker_exhaustion, defender_exhaustion FROM wars \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let participants: Vec<(i64, String, String)> = sqlx::query_as(
+            "SELECT war_id, side, country FROM war_participants \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut wars = Vec::with_capacity(rows.len());
+        for (
+            id,
+            name,
+            start_date,
+            attacker_war_goal,
+            defender_war_goal,
+            attacker_exhaustion,
+            defender_exhaustion,
+        ) in rows
+        {
+            wars.push(WarData {
+                id,
+                name,
+                start_date: StellarisDate::from_str(&start_date)?,
+                attackers: vec![],
+                defenders: vec![],
+                attacker_war_goal,
+                defender_war_goal,
+                attacker_exhaustion,
+                defender_exhaustion,
+            });
+        }
+        for (war_id, side, country) in participants {
+            if let Some(war) = wars.iter_mut().find(|war| war.id == war_id) {
+                match side.as_str() {
+                    ATTACKER => war.attackers.push(country),
+                    DEFENDER => war.defenders.push(country),
+                    _ => {}
+                }
+            }
+        }
+        Ok(wars)
+    }
+
+    async fn get_truces(&self, snapshot_id: i64) -> Result<Vec<TruceData>> {
+        let rows: Vec<TruceRow> = sqlx::query_as(
+            "SELECT truce_id, name, start_date, truce_type FROM truces \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let parties: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT truce_id, country FROM truce_parties \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut truces = Vec::with_capacity(rows.len());
+        for (id, name, start_date, truce_type) in rows {
+            truces.push(TruceData {
+                id,
+                name,
+                start_date: StellarisDate::from_str(&start_date)?,
+                truce_type,
+                countries: vec![],
+            });
+        }
+        for (truce_id, country) in parties {
+            if let Some(truce) = truces.iter_mut().find(|truce| truce.id == truce_id) {
+                truce.countries.push(country);
+            }
+        }
+        Ok(truces)
+    }
+
+    async fn get_federations(&self, snapshot_id: i64) -> Result<Vec<FederationData>> {
+        let rows: Vec<FederationRow> = sqlx::query_as(
+            "SELECT federation_id, name, federation_type, level, cohesion, leader \
+             FROM federations WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let members: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT federation_id, country FROM federation_members \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut federations: Vec<FederationData> = rows
+            .into_iter()
+            .map(
+                |(id, name, federation_type, level, cohesion, leader)| FederationData {
+                    id,
+                    name,
+                    federation_type,
+                    level,
+                    cohesion,
+                    leader,
+                    ..FederationData::default()
+                },
+            )
+            .collect();
+        for (federation_id, country) in members {
+            if let Some(federation) = federations
+                .iter_mut()
+                .find(|federation| federation.id == federation_id)
+            {
+                federation.members.push(country);
+            }
+        }
+        Ok(federations)
+    }
+
+    async fn get_galactic_community(&self, snapshot_id: i64) -> Result<GalacticCommunity> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT category, name FROM galactic_community \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut galactic_community = GalacticCommunity::default();
+        for (category, name) in rows {
+            match category.as_str() {
+                VOTING => galactic_community.voting = Some(name),
+                _ => {
+                    if let Some(names) =
+                        get_galactic_community_names_mut(&mut galactic_community, &category)
+                    {
+                        names.push(name);
+                    }
+                }
+            }
+        }
+        Ok(galactic_community)
+    }
+
+    async fn get_relations(&self, snapshot_id: i64) -> Result<Vec<RelationData>> {
+        let rows: Vec<RelationRow> = sqlx::query_as(
+            "SELECT position, owner, country, opinion, trust, rival, borders, contact \
+             FROM relations WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let treaties: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT relation, treaty FROM relation_treaties \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut relations: Vec<RelationData> = rows
+            .iter()
+            .map(
+                |(_, owner, country, opinion, trust, rival, borders, contact)| RelationData {
+                    owner: owner.clone(),
+                    country: country.clone(),
+                    opinion: *opinion,
+                    trust: *trust,
+                    rival: *rival,
+                    borders: *borders,
+                    contact: *contact,
+                    ..RelationData::default()
+                },
+            )
+            .collect();
+        for (relation, treaty) in treaties {
+            if let Some(index) = rows.iter().position(|row| row.0 == relation) {
+                relations[index].treaties.push(treaty);
+            }
+        }
+        Ok(relations)
+    }
+
+    async fn get_systems(&self, snapshot_id: i64) -> Result<Vec<SystemData>> {
+        let rows: Vec<SystemRow> = sqlx::query_as(
+            "SELECT system_id, name, x, y, star_class, owner, \
+             trade_collected, trade_throughput, piracy FROM systems \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let hyperlanes: Vec<(i64, i64)> = sqlx::query_as(
+            "SELECT system_id, destination FROM hyperlanes \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let collections: Vec<(i64, i64)> = sqlx::query_as(
+            "SELECT system_id, source FROM trade_collections \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut systems: Vec<SystemData> = rows
+            .into_iter()
+            .map(
+                |(id, name, x, y, star_class, owner, collected, throughput, piracy)| SystemData {
+                    id,
+                    name,
+                    x,
+                    y,
+                    star_class,
+                    owner,
+                    trade: TradeHubData {
+                        collected,
+                        throughput,
+                        piracy,
+                        ..TradeHubData::default()
+                    },
+                    ..SystemData::default()
+                },
+            )
+            .collect();
+        let positions: HashMap<i64, usize> = systems
+            .iter()
+            .enumerate()
+            .map(|(position, system)| (system.id, position))
+            .collect();
+        for (system_id, destination) in hyperlanes {
+            if let Some(position) = positions.get(&system_id) {
+                systems[*position].hyperlanes.push(destination);
+            }
+        }
+        for (system_id, source) in collections {
+            if let Some(position) = positions.get(&system_id) {
+                systems[*position].trade.collected_from.push(source);
+            }
+        }
+        Ok(systems)
+    }
+
+    async fn get_megastructures(&self, snapshot_id: i64) -> Result<Vec<MegastructureData>> {
+        let rows: Vec<MegastructureRow> = sqlx::query_as(
+            "SELECT megastructure_id, megastructure_type, owner, system FROM megastructures \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, megastructure_type, owner, system)| MegastructureData {
+                    id,
+                    megastructure_type,
+                    owner,
+                    system,
+                },
+            )
+            .collect())
+    }
+
+    async fn get_trade_routes(&self, snapshot_id: i64) -> Result<Vec<TradeRouteData>> {
+        let rows: Vec<TradeRouteRow> = sqlx::query_as(
+            "SELECT route_id, owner, from_system, to_system, delivered FROM trade_routes \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let paths: Vec<(i64, i64)> = sqlx::query_as(
+            "SELECT route_id, system_id FROM trade_route_paths \
+             WHERE snapshot_id = ? ORDER BY position",
+        )
+        .bind(snapshot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut routes: Vec<TradeRouteData> = rows
+            .into_iter()
+            .map(|(id, owner, from, to, delivered)| TradeRouteData {
+                id,
+                owner,
+                from,
+                to,
+                delivered,
+                ..TradeRouteData::default()
+            })
+            .collect();
+        for (route_id, system_id) in paths {
+            if let Some(route) = routes.iter_mut().find(|route| route.id == route_id) {
+                route.path.push(system_id);
+            }
+        }
+        Ok(routes)
+    }
+
+    async fn get_empires(&self, filter: EmpireFilter<'_>) -> Result<Vec<EmpireData>> {
+        let rows: Vec<EmpireRow> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empires.id, empires.name, happiness, military_power, economy_power, \
+                 tech_power, victory_score, victory_rank, empire_size, fleet_size, \
+                 starbase_capacity, trade_value FROM empires \
+                 INNER JOIN snapshots ON snapshots.id = empires.snapshot_id \
+                 WHERE {} ORDER BY snapshots.date, snapshots.id, empires.id",
+                filter.condition("empires.id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut budgets = self.get_budgets(filter).await?;
+        let mut resources = self.get_resources(filter).await?;
+        let mut planets = self.get_planets(filter).await?;
+        let mut demographics = self.get_demographics(filter).await?;
+        let mut fleets = self.get_fleets(filter).await?;
+        let mut tech = self.get_tech_status(filter).await?;
+        let mut leaders = self.get_leaders(filter).await?;
+        let mut identities = self.get_identities(filter).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    id,
+                    name,
+                    happiness,
+                    military_power,
+                    economy_power,
+                    tech_power,
+                    victory_score,
+                    victory_rank,
+                    empire_size,
+                    fleet_size,
+                    starbase_capacity,
+                    trade_value,
+                )| EmpireData {
+                    name,
+                    budget: budgets.remove(&id).unwrap_or_default(),
+                    resources: resources.remove(&id).unwrap_or_default(),
+                    planets: planets.remove(&id).unwrap_or_default(),
+                    demographics: Demographics {
+                        happiness,
+                        ..demographics.remove(&id).unwrap_or_default()
+                    },
+                    fleets: fleets.remove(&id).unwrap_or_default(),
+                    tech: tech.remove(&id).unwrap_or_default(),
+                    leaders: leaders.remove(&id).unwrap_or_default(),
+                    identity: identities.remove(&id).unwrap_or_default(),
+                    military_power,
+                    economy_power,
+                    tech_power,
+                    victory_score,
+                    victory_rank,
+                    empire_size,
+                    fleet_size,
+                    starbase_capacity,
+                    trade_value,
+                },
+            )
+            .collect())
+    }
+
+    async fn get_resources(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, Resources>> {
+        let rows: Vec<(i64, String, f64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, resource, amount FROM resources WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut resources: HashMap<i64, Resources> = HashMap::new();
+        for (empire_id, resource, amount) in rows {
+            resources
+                .entry(empire_id)
+                .or_default()
+                .set(&ResourceClass::from_str(&resource)?, amount);
+        }
+        Ok(resources)
+    }
+
+    async fn get_planets(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, Vec<PlanetData>>> {
+        let rows: Vec<PlanetRow> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, planet_id, name, class, size, owner, controller, pops, \
+                 stability, amenities, housing, crime FROM planets \
+                 WHERE {} ORDER BY position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut districts = self
+            .get_planet_slots(filter, "planet_districts", "district")
+            .await?;
+        let mut buildings = self
+            .get_planet_slots(filter, "planet_buildings", "building")
+            .await?;
+
+        let mut planets: HashMap<i64, Vec<PlanetData>> = HashMap::new();
+        for (
+            empire_id,
+            id,
+            name,
+            class,
+            size,
+            owner,
+            controller,
+            pops,
+            stability,
+            amenities,
+            housing,
+            crime,
+        ) in rows
+        {
+            planets.entry(empire_id).or_default().push(PlanetData {
+                id,
+                name,
+                class,
+                size,
+                owner,
+                controller,
+                pops,
+                districts: districts.remove(&(empire_id, id)).unwrap_or_default(),
+                buildings: buildings.remove(&(empire_id, id)).unwrap_or_default(),
+                stability,
+                amenities,
+                housing,
+                crime,
+            });
+        }
+        Ok(planets)
+    }
+
+    async fn get_planet_slots(
+        &self,
+        filter: EmpireFilter<'_>,
+        table: &str,
+        column: &str,
+    ) -> Result<HashMap<(i64, i64), Vec<String>>> {
+        let rows: Vec<(i64, i64, String)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, planet_id, {} FROM {} WHERE {} ORDER BY planet_id, position",
+                column,
+                table,
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut slots: HashMap<(i64, i64), Vec<String>> = HashMap::new();
+        for (empire_id, planet_id, slot) in rows {
+            slots.entry((empire_id, planet_id)).or_default().push(slot);
+        }
+        Ok(slots)
+    }
+
+    async fn get_demographics(
+        &self,
+        filter: EmpireFilter<'_>,
+    ) -> Result<HashMap<i64, Demographics>> {
+        let rows: Vec<(i64, String, String, i64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, category, name, pops FROM demographics WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut demographics: HashMap<i64, Demographics> = HashMap::new();
+        for (empire_id, category, name, pops) in rows {
+            let empire = demographics.entry(empire_id).or_default();
+            if let Some(map) = get_demographic_map_mut(empire, &category) {
+                map.insert(name, pops);
+            }
+        }
+        Ok(demographics)
+    }
+
+    async fn get_fleets(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, Vec<FleetData>>> {
+        let rows: Vec<(i64, i64, String, i64, f64, i64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, fleet_id, name, ships, military_power, system FROM fleets \
+                 WHERE {} ORDER BY position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+        let classes: Vec<(i64, i64, String, i64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, fleet_id, class, ships FROM fleet_classes WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut fleets: HashMap<i64, Vec<FleetData>> = HashMap::new();
+        for (empire_id, id, name, ships, military_power, system) in rows {
+            fleets.entry(empire_id).or_default().push(FleetData {
+                id,
+                name,
+                ships,
+                military_power,
+                system,
+                ..FleetData::default()
+            });
+        }
+        for (empire_id, fleet_id, class, ships) in classes {
+            if let Some(fleet) = fleets
+                .get_mut(&empire_id)
+                .and_then(|fleets| fleets.iter_mut().find(|fleet| fleet.id == fleet_id))
+            {
+                fleet.classes.insert(class, ships);
+            }
+        }
+        Ok(fleets)
+    }
+
+    async fn get_leaders(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, Vec<LeaderData>>> {
+        let rows: Vec<LeaderRow> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, leader_id, name, class, level, age, assignment, location, \
+                 owner FROM leaders WHERE {} ORDER BY position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+        let traits: Vec<(i64, i64, String)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, leader_id, name FROM leader_traits WHERE {} ORDER BY position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut leaders: HashMap<i64, Vec<LeaderData>> = HashMap::new();
+        for (empire_id, id, name, class, level, age, assignment, location, owner) in rows {
+            leaders.entry(empire_id).or_default().push(LeaderData {
+                id,
+                name,
+                class,
+                level,
+                age,
+                assignment,
+                location,
+                owner,
+                ..LeaderData::default()
+            });
+        }
+        for (empire_id, leader_id, name) in traits {
+            if let Some(leader) = leaders
+                .get_mut(&empire_id)
+                .and_then(|leaders| leaders.iter_mut().find(|leader| leader.id == leader_id))
+            {
+                leader.traits.push(name);
+            }
+        }
+        Ok(leaders)
+    }
+
+    async fn get_identities(
+        &self,
+        filter: EmpireFilter<'_>,
+    ) -> Result<HashMap<i64, EmpireIdentity>> {
+        let rows: Vec<IdentityRow> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, government, authority, origin, ruler FROM empire_identities \
+                 WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+        let names: Vec<(i64, String, String)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, category, name FROM empire_identity_names \
+                 WHERE {} ORDER BY category, position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+        let policies: Vec<(i64, String, String, Option<String>)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, policy, selected, date FROM policies \
+                 WHERE {} ORDER BY position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut identities: HashMap<i64, EmpireIdentity> = rows
+            .into_iter()
+            .map(|(empire_id, government, authority, origin, ruler)| {
+                (
+                    empire_id,
+                    EmpireIdentity {
+                        government,
+                        authority,
+                        origin,
+                        ruler,
+                        ..EmpireIdentity::default()
+                    },
+                )
+            })
+            .collect();
+        for (empire_id, category, name) in names {
+            let identity = identities.entry(empire_id).or_default();
+            if let Some(names) = get_identity_names_mut(identity, &category) {
+                names.push(name);
+            }
+        }
+        for (empire_id, policy, selected, date) in policies {
+            identities
+                .entry(empire_id)
+                .or_default()
+                .policies
+                .push(PolicyData {
+                    policy,
+                    selected,
+                    date: date
+                        .map(|date| StellarisDate::from_str(&date))
+                        .transpose()?,
+                });
+        }
+        Ok(identities)
+    }
+
+    async fn get_tech_status(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, TechStatus>> {
+        let technologies: Vec<(i64, String, i64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, technology, level FROM technologies WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+        let research: Vec<(i64, String, String, f64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, area, technology, progress FROM research WHERE {}",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut tech: HashMap<i64, TechStatus> = HashMap::new();
+        for (empire_id, technology, level) in technologies {
+            tech.entry(empire_id)
+                .or_default()
+                .researched
+                .insert(technology, level);
+        }
+        for (empire_id, area, technology, progress) in research {
+            if let Some(current) = get_research_mut(tech.entry(empire_id).or_default(), &area) {
+                *current = Some(ResearchData {
+                    technology,
+                    progress,
+                });
+            }
+        }
+        Ok(tech)
+    }
+
+    async fn get_budgets(&self, filter: EmpireFilter<'_>) -> Result<HashMap<i64, Budget>> {
+        let rows: Vec<(i64, String, String, String, String, f64)> = filter
+            .bind(sqlx::query_as(&format!(
+                "SELECT empire_id, month, component, resource, contributor, amount \
+                 FROM budget_lines WHERE {} ORDER BY month, component, resource, position",
+                filter.condition("empire_id")
+            )))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut budgets: HashMap<i64, Budget> = HashMap::new();
+        for (empire_id, month, component, resource, contributor, amount) in rows {
+            let component = BudgetComponent::from_str(&component)?;
+            let budget = budgets.entry(empire_id).or_default();
+            if let Some(map) = get_budget_map_mut(budget, &month, &component) {
+                map.entry(ResourceClass::from_str(&resource)?)
+                    .or_insert_with(Vec::new)
+                    .push((contributor, amount));
+            }
+        }
+        Ok(budgets)
+    }
+}
+
+#[async_trait]
+impl DataCoreBackend for DataCore {
+    async fn store_snapshot(
+        &self,
+        data_point: &ModelDataPoint,
+        policy: DuplicatePolicy,
+    ) -> Result<()> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query("INSERT OR IGNORE INTO campaigns (name) VALUES (?)")
+            .bind(&data_point.campaign_name)
+            .execute(&mut transaction)
+            .await?;
+        let (campaign_id,): (i64,) = sqlx::query_as("SELECT id FROM campaigns WHERE name = ?")
+            .bind(&data_point.campaign_name)
+            .fetch_one(&mut transaction)
+            .await?;
+
+        let existing: Option<(i64,)> =
+            sqlx::query_as("SELECT id FROM snapshots WHERE campaign_id = ? AND date = ?")
+                .bind(campaign_id)
+                .bind(data_point.date.to_string())
+                .fetch_optional(&mut transaction)
+                .await?;
+
+        match (existing, policy) {
+            (Some(_), DuplicatePolicy::KeepFirst) => return Ok(()),
+            // Everything stored for the snapshot goes with it through ON DELETE CASCADE.
+            (Some((snapshot_id,)), DuplicatePolicy::Replace) => {
+                sqlx::query("DELETE FROM snapshots WHERE id = ?")
+                    .bind(snapshot_id)
+                    .execute(&mut transaction)
+                    .await?;
+            }
+            (None, _) => {}
+        }
+
+        let snapshot_id = sqlx::query("INSERT INTO snapshots (campaign_id, date) VALUES (?, ?)")
+            .bind(campaign_id)
+            .bind(data_point.date.to_string())
+            .execute(&mut transaction)
+            .await?
+            .last_insert_rowid();
+
+        for empire in data_point.empires.iter() {
+            insert_empire(&mut transaction, snapshot_id, empire).await?;
+        }
+
+        for (position, war) in data_point.wars.iter().enumerate() {
+            insert_war(&mut transaction, snapshot_id, position, war).await?;
+        }
+
+        for (position, truce) in data_point.truces.iter().enumerate() {
+            insert_truce(&mut transaction, snapshot_id, position, truce).await?;
+        }
+
+        for (position, federation) in data_point.federations.iter().enumerate() {
+            insert_federation(&mut transaction, snapshot_id, position, federation).await?;
+        }
+
+        insert_galactic_community(
+            &mut transaction,
+            snapshot_id,
+            &data_point.galactic_community,
+        )
+        .await?;
+
+        for (position, relation) in data_point.relations.iter().enumerate() {
+            insert_relation(&mut transaction, snapshot_id, position, relation).await?;
+        }
+
+        for (position, system) in data_point.systems.iter().enumerate() {
+            insert_system(&mut transaction, snapshot_id, position, system).await?;
+        }
+
+        insert_market(&mut transaction, snapshot_id, &data_point.market).await?;
+
+        for (position, route) in data_point.trade_routes.iter().enumerate() {
+            insert_trade_route(&mut transaction, snapshot_id, position, route).await?;
+        }
+
+        for (position, megastructure) in data_point.megastructures.iter().enumerate() {
+            insert_megastructure(&mut transaction, snapshot_id, position, megastructure).await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn get_snapshots(&self) -> Result<Vec<ModelDataPoint>> {
+        // Snapshots stored before dates were recorded have an empty date and cannot be placed in
+        // a campaign's history, so they are left out.
+        let snapshots: Vec<(i64, String, String)> = sqlx::query_as(
+            "SELECT snapshots.id, campaigns.name, snapshots.date FROM snapshots \
+             INNER JOIN campaigns ON campaigns.id = snapshots.campaign_id \
+             WHERE snapshots.date <> '' \
+             ORDER BY snapshots.date, snapshots.id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut data_points = Vec::with_capacity(snapshots.len());
+        for (snapshot_id, campaign_name, date) in snapshots {
+            data_points.push(ModelDataPoint {
+                campaign_name,
+                date: StellarisDate::from_str(&date)?,
+                empires: self
+                    .get_empires(EmpireFilter::Snapshot(snapshot_id))
+                    .await?,
+                wars: self.get_wars(snapshot_id).await?,
+                truces: self.get_truces(snapshot_id).await?,
+                federations: self.get_federations(snapshot_id).await?,
+                galactic_community: self.get_galactic_community(snapshot_id).await?,
+                relations: self.get_relations(snapshot_id).await?,
+                systems: self.get_systems(snapshot_id).await?,
+                market: self.get_market(snapshot_id).await?,
+                trade_routes: self.get_trade_routes(snapshot_id).await?,
+                megastructures: self.get_megastructures(snapshot_id).await?,
+            });
+        }
+        Ok(data_points)
+    }
+
+    async fn get_campaign_names(&self) -> Result<Vec<String>> {
+        let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM campaigns ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(names.into_iter().map(|(name,)| name).collect())
+    }
+
+    async fn get_current_campaign_name(&self) -> Result<Option<String>> {
+        let name: Option<(String,)> = sqlx::query_as(
+            "SELECT campaigns.name FROM snapshots \
+             INNER JOIN campaigns ON campaigns.id = snapshots.campaign_id \
+             WHERE snapshots.date <> '' \
+             ORDER BY snapshots.id DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(name.map(|(name,)| name))
+    }
+
+    async fn get_empire_series(
+        &self,
+        campaign_name: &str,
+        empire_name: &str,
+    ) -> Result<Vec<EmpireData>> {
+        self.get_empires(EmpireFilter::Series {
+            campaign_name,
+            empire_name,
+        })
+        .await
+    }
+
+    async fn delete_campaign(&self, campaign_name: &str) -> Result<()> {
+        sqlx::query("DELETE FROM campaigns WHERE name = ?")
+            .bind(campaign_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+async fn insert_empire(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    empire: &EmpireData,
+) -> Result<()> {
+    let empire_id = sqlx::query(
+        "INSERT INTO empires \
+         (snapshot_id, name, happiness, military_power, economy_power, tech_power, \
+         victory_score, victory_rank, empire_size, fleet_size, starbase_capacity, trade_value) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(&empire.name)
+    .bind(empire.demographics.happiness)
+    .bind(empire.military_power)
+    .bind(empire.economy_power)
+    .bind(empire.tech_power)
+    .bind(empire.victory_score)
+    .bind(empire.victory_rank)
+    .bind(empire.empire_size)
+    .bind(empire.fleet_size)
+    .bind(empire.starbase_capacity)
+    .bind(empire.trade_value)
+    .execute(&mut *transaction)
+    .await?
+    .last_insert_rowid();
+
+    for class in ResourceClass::iter() {
+        sqlx::query("INSERT INTO resources (empire_id, resource, amount) VALUES (?, ?, ?)")
+            .bind(empire_id)
+            .bind(class.to_string())
+            .bind(empire.resources.get(&class))
+            .execute(&mut *transaction)
+            .await?;
+    }
+
+    for (month, component, map) in get_budget_maps(&empire.budget) {
+        for (class, contributions) in map.iter() {
+            for (position, (contributor, amount)) in contributions.iter().enumerate() {
+                sqlx::query(
+                    "INSERT INTO budget_lines \
+                     (empire_id, month, component, resource, position, contributor, amount) \
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(empire_id)
+                .bind(month)
+                .bind(component.to_string())
+                .bind(class.to_string())
+                .bind(position as i64)
+                .bind(contributor)
+                .bind(amount)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+    }
+
+    for (position, planet) in empire.planets.iter().enumerate() {
+        insert_planet(transaction, empire_id, position, planet).await?;
+    }
+
+    for (position, fleet) in empire.fleets.iter().enumerate() {
+        insert_fleet(transaction, empire_id, position, fleet).await?;
+    }
+
+    for (position, leader) in empire.leaders.iter().enumerate() {
+        insert_leader(transaction, empire_id, position, leader).await?;
+    }
+
+    insert_identity(transaction, empire_id, &empire.identity).await?;
+
+    for (technology, level) in empire.tech.researched.iter() {
+        sqlx::query("INSERT INTO technologies (empire_id, technology, level) VALUES (?, ?, ?)")
+            .bind(empire_id)
+            .bind(technology)
+            .bind(level)
+            .execute(&mut *transaction)
+            .await?;
+    }
+
+    for (area, research) in get_research(&empire.tech) {
+        if let Some(research) = research {
+            sqlx::query(
+                "INSERT INTO research (empire_id, area, technology, progress) VALUES (?, ?, ?, ?)",
+            )
+            .bind(empire_id)
+            .bind(area)
+            .bind(&research.technology)
+            .bind(research.progress)
+            .execute(&mut *transaction)
+            .await?;
+        }
+    }
+
+    for (category, map) in get_demographic_maps(&empire.demographics) {
+        for (name, pops) in map.iter() {
+            sqlx::query(
+                "INSERT INTO demographics (empire_id, category, name, pops) VALUES (?, ?, ?, ?)",
+            )
+            .bind(empire_id)
+            .bind(category)
+            .bind(name)
+            .bind(pops)
+            .execute(&mut *transaction)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn insert_planet(
+    transaction: &mut Transaction<'_, Sqlite>,
+    empire_id: i64,
+    position: usize,
+    planet: &PlanetData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO planets \
+         (empire_id, planet_id, position, name, class, size, owner, controller, pops, \
+         stability, amenities, housing, crime) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(empire_id)
+    .bind(planet.id)
+    .bind(position as i64)
+    .bind(&planet.name)
+    .bind(&planet.class)
+    .bind(planet.size)
+    .bind(planet.owner)
+    .bind(planet.controller)
+    .bind(planet.pops)
+    .bind(planet.stability)
+    .bind(planet.amenities)
+    .bind(planet.housing)
+    .bind(planet.crime)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, district) in planet.districts.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO planet_districts (empire_id, planet_id, position, district) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(empire_id)
+        .bind(planet.id)
+        .bind(position as i64)
+        .bind(district)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for (position, building) in planet.buildings.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO planet_buildings (empire_id, planet_id, position, building) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(empire_id)
+        .bind(planet.id)
+        .bind(position as i64)
+        .bind(building)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_fleet(
+    transaction: &mut Transaction<'_, Sqlite>,
+    empire_id: i64,
+    position: usize,
+    fleet: &FleetData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO fleets \
+         (empire_id, fleet_id, position, name, ships, military_power, system) \
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(empire_id)
+    .bind(fleet.id)
+    .bind(position as i64)
+    .bind(&fleet.name)
+    .bind(fleet.ships)
+    .bind(fleet.military_power)
+    .bind(fleet.system)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (class, ships) in fleet.classes.iter() {
+        sqlx::query(
+            "INSERT INTO fleet_classes (empire_id, fleet_id, class, ships) VALUES (?, ?, ?, ?)",
+        )
+        .bind(empire_id)
+        .bind(fleet.id)
+        .bind(class)
+        .bind(ships)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_leader(
+    transaction: &mut Transaction<'_, Sqlite>,
+    empire_id: i64,
+    position: usize,
+    leader: &LeaderData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO leaders \
+         (empire_id, leader_id, position, name, class, level, age, assignment, location, owner) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(empire_id)
+    .bind(leader.id)
+    .bind(position as i64)
+    .bind(&leader.name)
+    .bind(&leader.class)
+    .bind(leader.level)
+    .bind(leader.age)
+    .bind(&leader.assignment)
+    .bind(leader.location)
+    .bind(leader.owner)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, name) in leader.traits.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO leader_traits (empire_id, leader_id, position, name) VALUES (?, ?, ?, ?)",
+        )
+        .bind(empire_id)
+        .bind(leader.id)
+        .bind(position as i64)
+        .bind(name)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_identity(
+    transaction: &mut Transaction<'_, Sqlite>,
+    empire_id: i64,
+    identity: &EmpireIdentity,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO empire_identities (empire_id, government, authority, origin, ruler) \
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(empire_id)
+    .bind(&identity.government)
+    .bind(&identity.authority)
+    .bind(&identity.origin)
+    .bind(identity.ruler)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (category, names) in get_identity_names(identity) {
+        for (position, name) in names.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO empire_identity_names (empire_id, category, position, name) \
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(empire_id)
+            .bind(category)
+            .bind(position as i64)
+            .bind(name)
+            .execute(&mut *transaction)
+            .await?;
+        }
+    }
+
+    for (position, policy) in identity.policies.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO policies (empire_id, position, policy, selected, date) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(empire_id)
+        .bind(position as i64)
+        .bind(&policy.policy)
+        .bind(&policy.selected)
+        .bind(policy.date.map(|date| date.to_string()))
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_war(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    war: &WarData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO wars \
+         (snapshot_id, war_id, position, name, start_date, attacker_war_goal, \
+         defender_war_goal, attacker_exhaustion, defender_exhaustion) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(war.id)
+    .bind(position as i64)
+    .bind(&war.name)
+    .bind(war.start_date.to_string())
+    .bind(&war.attacker_war_goal)
+    .bind(&war.defender_war_goal)
+    .bind(war.attacker_exhaustion)
+    .bind(war.defender_exhaustion)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (side, countries) in [(ATTACKER, &war.attackers), (DEFENDER, &war.defenders)] {
+        for (position, country) in countries.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO war_participants (snapshot_id, war_id, side, position, country) \
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(snapshot_id)
+            .bind(war.id)
+            .bind(side)
+            .bind(position as i64)
+            .bind(country)
+            .execute(&mut *transaction)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn insert_truce(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    truce: &TruceData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO truces (snapshot_id, truce_id, position, name, start_date, truce_type) \
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(truce.id)
+    .bind(position as i64)
+    .bind(&truce.name)
+    .bind(truce.start_date.to_string())
+    .bind(&truce.truce_type)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, country) in truce.countries.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO truce_parties (snapshot_id, truce_id, position, country) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(truce.id)
+        .bind(position as i64)
+        .bind(country)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_federation(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    federation: &FederationData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO federations \
+         (snapshot_id, federation_id, position, name, federation_type, level, cohesion, leader) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(federation.id)
+    .bind(position as i64)
+    .bind(&federation.name)
+    .bind(&federation.federation_type)
+    .bind(federation.level)
+    .bind(federation.cohesion)
+    .bind(&federation.leader)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, country) in federation.members.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO federation_members (snapshot_id, federation_id, position, country) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(federation.id)
+        .bind(position as i64)
+        .bind(country)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_galactic_community(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    galactic_community: &GalacticCommunity,
+) -> Result<()> {
+    let voting = galactic_community
+        .voting
+        .iter()
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut categories = get_galactic_community_names(galactic_community);
+    categories.push((VOTING, &voting));
+
+    for (category, names) in categories {
+        for (position, name) in names.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO galactic_community (snapshot_id, category, position, name) \
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(snapshot_id)
+            .bind(category)
+            .bind(position as i64)
+            .bind(name)
+            .execute(&mut *transaction)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn insert_relation(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    relation: &RelationData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO relations \
+         (snapshot_id, position, owner, country, opinion, trust, rival, borders, contact) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(position as i64)
+    .bind(&relation.owner)
+    .bind(&relation.country)
+    .bind(relation.opinion)
+    .bind(relation.trust)
+    .bind(relation.rival)
+    .bind(relation.borders)
+    .bind(relation.contact)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (treaty_position, treaty) in relation.treaties.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO relation_treaties (snapshot_id, relation, position, treaty) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(position as i64)
+        .bind(treaty_position as i64)
+        .bind(treaty)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_system(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    system: &SystemData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO systems \
+         (snapshot_id, system_id, position, name, x, y, star_class, owner, \
+         trade_collected, trade_throughput, piracy) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(system.id)
+    .bind(position as i64)
+    .bind(&system.name)
+    .bind(system.x)
+    .bind(system.y)
+    .bind(&system.star_class)
+    .bind(&system.owner)
+    .bind(system.trade.collected)
+    .bind(system.trade.throughput)
+    .bind(system.trade.piracy)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, destination) in system.hyperlanes.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO hyperlanes (snapshot_id, system_id, position, destination) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(system.id)
+        .bind(position as i64)
+        .bind(destination)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for (position, source) in system.trade.collected_from.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO trade_collections (snapshot_id, system_id, position, source) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(system.id)
+        .bind(position as i64)
+        .bind(source)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_megastructure(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    megastructure: &MegastructureData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO megastructures \
+         (snapshot_id, megastructure_id, position, megastructure_type, owner, system) \
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(megastructure.id)
+    .bind(position as i64)
+    .bind(&megastructure.megastructure_type)
+    .bind(&megastructure.owner)
+    .bind(megastructure.system)
+    .execute(&mut *transaction)
+    .await?;
+    Ok(())
+}
+
+async fn insert_trade_route(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    position: usize,
+    route: &TradeRouteData,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO trade_routes \
+         (snapshot_id, route_id, position, owner, from_system, to_system, delivered) \
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot_id)
+    .bind(route.id)
+    .bind(position as i64)
+    .bind(&route.owner)
+    .bind(route.from)
+    .bind(route.to)
+    .bind(route.delivered)
+    .execute(&mut *transaction)
+    .await?;
+
+    for (position, system_id) in route.path.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO trade_route_paths (snapshot_id, route_id, position, system_id) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(route.id)
+        .bind(position as i64)
+        .bind(system_id)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_market(
+    transaction: &mut Transaction<'_, Sqlite>,
+    snapshot_id: i64,
+    market: &MarketData,
+) -> Result<()> {
+    for (position, resource) in market.resources.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO market_prices (snapshot_id, position, resource, fluctuation, price) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(position as i64)
+        .bind(resource.resource.to_string())
+        .bind(resource.fluctuation)
+        .bind(resource.price)
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    for (position, trade) in market.trades.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO market_trades (snapshot_id, position, country, resource, bought, sold) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(snapshot_id)
+        .bind(position as i64)
+        .bind(&trade.country)
+        .bind(trade.resource.to_string())
+        .bind(trade.bought)
+        .bind(trade.sold)
+        .execute(&mut *transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+fn get_galactic_community_names(
+    galactic_community: &GalacticCommunity,
+) -> Vec<(&str, &Vec<String>)> {
+    vec![
+        (MEMBER, &galactic_community.members),
+        (COUNCIL, &galactic_community.council),
+        (RESOLUTION, &galactic_community.resolutions),
+    ]
+}
+
+fn get_galactic_community_names_mut<'a>(
+    galactic_community: &'a mut GalacticCommunity,
+    category: &str,
+) -> Option<&'a mut Vec<String>> {
+    match category {
+        MEMBER => Some(&mut galactic_community.members),
+        COUNCIL => Some(&mut galactic_community.council),
+        RESOLUTION => Some(&mut galactic_community.resolutions),
+        _ => None,
+    }
+}
+
+fn get_identity_names(identity: &EmpireIdentity) -> Vec<(&str, &Vec<String>)> {
+    vec![(CIVIC, &identity.civics), (ETHIC, &identity.ethics)]
+}
+
+fn get_identity_names_mut<'a>(
+    identity: &'a mut EmpireIdentity,
+    category: &str,
+) -> Option<&'a mut Vec<String>> {
+    match category {
+        CIVIC => Some(&mut identity.civics),
+        ETHIC => Some(&mut identity.ethics),
+        _ => None,
+    }
+}
+
+fn get_budget_maps(budget: &Budget) -> Vec<(&str, BudgetComponent, &BudgetMap)> {
+    vec![
+        (CURRENT_MONTH, BudgetComponent::Income, &budget.income),
+        (CURRENT_MONTH, BudgetComponent::Expenses, &budget.expense),
+        (CURRENT_MONTH, BudgetComponent::Balance, &budget.balance),
+        (
+            LAST_MONTH,
+            BudgetComponent::Income,
+            &budget.income_last_month,
+        ),
+        (
+            LAST_MONTH,
+            BudgetComponent::Expenses,
+            &budget.expense_last_month,
+        ),
+        (
+            LAST_MONTH,
+            BudgetComponent::Balance,
+            &budget.balance_last_month,
+        ),
+    ]
+}
+
+fn get_budget_map_mut<'a>(
+    budget: &'a mut Budget,
+    month: &str,
+    component: &BudgetComponent,
+) -> Option<&'a mut BudgetMap> {
+    match (month, component) {
+        (CURRENT_MONTH, BudgetComponent::Income) => Some(&mut budget.income),
+        (CURRENT_MONTH, BudgetComponent::Expenses) => Some(&mut budget.expense),
+        (CURRENT_MONTH, BudgetComponent::Balance) => Some(&mut budget.balance),
+        (LAST_MONTH, BudgetComponent::Income) => Some(&mut budget.income_last_month),
+        (LAST_MONTH, BudgetComponent::Expenses) => Some(&mut budget.expense_last_month),
+        (LAST_MONTH, BudgetComponent::Balance) => Some(&mut budget.balance_last_month),
+        _ => None,
+    }
+}
+
+fn get_demographic_maps(demographics: &Demographics) -> Vec<(&str, &HashMap<String, i64>)> {
+    vec![
+        (SPECIES, &demographics.species),
+        (JOB, &demographics.jobs),
+        (STRATUM, &demographics.strata),
+        (ETHIC, &demographics.ethics),
+    ]
+}
+
+fn get_demographic_map_mut<'a>(
+    demographics: &'a mut Demographics,
+    category: &str,
+) -> Option<&'a mut HashMap<String, i64>> {
+    match category {
+        SPECIES => Some(&mut demographics.species),
+        JOB => Some(&mut demographics.jobs),
+        STRATUM => Some(&mut demographics.strata),
+        ETHIC => Some(&mut demographics.ethics),
+        _ => None,
+    }
+}
+
+fn get_research(tech: &TechStatus) -> Vec<(&str, &Option<ResearchData>)> {
+    vec![
+        (PHYSICS, &tech.physics),
+        (SOCIETY, &tech.society),
+        (ENGINEERING, &tech.engineering),
+    ]
+}
+
+fn get_research_mut<'a>(
+    tech: &'a mut TechStatus,
+    area: &str,
+) -> Option<&'a mut Option<ResearchData>> {
+    match area {
+        PHYSICS => Some(&mut tech.physics),
+        SOCIETY => Some(&mut tech.society),
+        ENGINEERING => Some(&mut tech.engineering),
+        _ => None,
+    }
 }
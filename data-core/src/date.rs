@@ -0,0 +1,148 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Error, Result};
+
+const MONTHS_PER_YEAR: i32 = 12;
+const DAYS_PER_MONTH: u32 = 30;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct StellarisDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl StellarisDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self> {
+        if !(1..=MONTHS_PER_YEAR as u32).contains(&month) {
+            bail!("Invalid month {} in date {}.{}.{}", month, year, month, day);
+        }
+        if !(1..=DAYS_PER_MONTH).contains(&day) {
+            bail!("Invalid day {} in date {}.{}.{}", day, year, month, day);
+        }
+        Ok(StellarisDate { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn add_months(&self, months: i32) -> Self {
+        let total = self.month_index() + months;
+        StellarisDate {
+            year: total.div_euclid(MONTHS_PER_YEAR),
+            month: total.rem_euclid(MONTHS_PER_YEAR) as u32 + 1,
+            day: self.day,
+        }
+    }
+
+    pub fn months_until(&self, other: &StellarisDate) -> i32 {
+        other.month_index() - self.month_index()
+    }
+
+    pub fn days_until(&self, other: &StellarisDate) -> i64 {
+        self.months_until(other) as i64 * DAYS_PER_MONTH as i64 + other.day as i64 - self.day as i64
+    }
+
+    fn month_index(&self) -> i32 {
+        self.year * MONTHS_PER_YEAR + self.month as i32 - 1
+    }
+}
+
+impl fmt::Display for StellarisDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}.{:02}.{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for StellarisDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts: Vec<&str> = s.split('.').collect();
+        match parts.as_slice() {
+            [year, month, day] => StellarisDate::new(year.parse()?, month.parse()?, day.parse()?),
+            _ => Err(anyhow!("Invalid date {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StellarisDate;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_str__given_save_date__returns_date() {
+        let date = StellarisDate::from_str("2200.03.01").unwrap();
+
+        assert_eq!(date, StellarisDate::new(2200, 3, 1).unwrap());
+        assert_eq!(date.to_string(), "2200.03.01");
+    }
+
+    #[test]
+    fn from_str__given_thirtieth_of_february__returns_date() {
+        let date = StellarisDate::from_str("2200.02.30").unwrap();
+
+        assert_eq!(date.day(), 30);
+    }
+
+    #[test]
+    fn from_str__given_malformed_date__returns_error() {
+        assert!(StellarisDate::from_str("2200.03").is_err());
+        assert!(StellarisDate::from_str("2200.13.01").is_err());
+        assert!(StellarisDate::from_str("2200.01.31").is_err());
+        assert!(StellarisDate::from_str("").is_err());
+    }
+
+    #[test]
+    fn cmp__given_dates__orders_by_year_month_day() {
+        let mut dates = vec![
+            StellarisDate::new(2201, 1, 1).unwrap(),
+            StellarisDate::new(2200, 12, 30).unwrap(),
+            StellarisDate::new(2200, 2, 1).unwrap(),
+            StellarisDate::new(2200, 1, 15).unwrap(),
+        ];
+
+        dates.sort();
+
+        assert_eq!(
+            dates,
+            vec![
+                StellarisDate::new(2200, 1, 15).unwrap(),
+                StellarisDate::new(2200, 2, 1).unwrap(),
+                StellarisDate::new(2200, 12, 30).unwrap(),
+                StellarisDate::new(2201, 1, 1).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_months__given_months_past_year_end__rolls_over_year() {
+        let date = StellarisDate::new(2200, 11, 5).unwrap();
+
+        assert_eq!(date.add_months(3), StellarisDate::new(2201, 2, 5).unwrap());
+        assert_eq!(
+            date.add_months(-11),
+            StellarisDate::new(2199, 12, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn days_until__given_later_date__counts_thirty_day_months() {
+        let start = StellarisDate::new(2200, 1, 1).unwrap();
+        let end = StellarisDate::new(2201, 2, 11).unwrap();
+
+        assert_eq!(start.months_until(&end), 13);
+        assert_eq!(start.days_until(&end), 13 * 30 + 10);
+        assert_eq!(end.days_until(&start), -(13 * 30 + 10));
+    }
+}
@@ -1,3 +1,12 @@
+mod data;
 mod data_core;
+mod date;
 
-pub use crate::data_core::{DataCore, DataCoreBackend};
+pub use crate::data::{
+    Budget, Demographics, EmpireData, EmpireIdentity, FederationData, FleetData, GalacticCommunity,
+    LeaderData, MarketData, MarketResourceData, MarketTradeData, MegastructureData, ModelDataPoint,
+    PlanetData, PolicyData, RelationData, ResearchData, Resources, SystemData, TechStatus,
+    TradeHubData, TradeRouteData, TruceData, WarData,
+};
+pub use crate::data_core::{DataCore, DataCoreBackend, DuplicatePolicy};
+pub use crate::date::StellarisDate;
@@ -3,14 +3,36 @@ mod tests {
     use std::{
         fs::{self, ReadDir},
         path::PathBuf,
+        str::FromStr,
     };
 
-    use data_core::DataCore;
+    use std::collections::HashMap;
+
+    use data_core::{
+        Budget, DataCore, DataCoreBackend, Demographics, DuplicatePolicy, EmpireData,
+        EmpireIdentity, FederationData, FleetData, GalacticCommunity, LeaderData, MarketData,
+        MarketResourceData, MarketTradeData, MegastructureData, ModelDataPoint, PlanetData,
+        PolicyData, RelationData, ResearchData, Resources, StellarisDate, SystemData, TechStatus,
+        TradeHubData, TradeRouteData, TruceData, WarData,
+    };
+    use sqlx::SqlitePool;
+    use stellarust::dto::ResourceClass;
     use test_helper::{cleanup_sqlite, create_sqlite_db, drop_sqlite_db, get_path};
 
     const TEST_WORKING_DIRECTORY_SOURCE: &str = "stellarust/res/test_data/data_core/";
     const NO_DB: &str = "NO_DB.db";
     const EXISTING_DB: &str = "EXISTING_DB.db";
+    const MISSING_DIR: &str = "MISSING_DIR";
+    const ROUND_TRIP_DB: &str = "ROUND_TRIP_DB.db";
+    const REOPENED_DB: &str = "REOPENED_DB.db";
+    const CAMPAIGNS_DB: &str = "CAMPAIGNS_DB.db";
+    const SERIES_DB: &str = "SERIES_DB.db";
+    const DELETE_DB: &str = "DELETE_DB.db";
+    const REPLACE_DB: &str = "REPLACE_DB.db";
+    const KEEP_FIRST_DB: &str = "KEEP_FIRST_DB.db";
+    const OUT_OF_ORDER_DB: &str = "OUT_OF_ORDER_DB.db";
+    const CURRENT_CAMPAIGN_DB: &str = "CURRENT_CAMPAIGN_DB.db";
+    const LEGACY_DB: &str = "LEGACY_DB.db";
 
     #[actix_rt::test]
     async fn create__given_path_no_db_at_path__sqlite_db_created_at_path() {
@@ -46,7 +68,9 @@ mod tests {
         fs::create_dir_all(test_working_dir_path.clone()).unwrap();
         cleanup_sqlite(&test_working_dir_path, &EXISTING_DB);
 
-        create_sqlite_db(&test_working_dir_path, &EXISTING_DB).unwrap();
+        create_sqlite_db(&test_working_dir_path, &EXISTING_DB)
+            .await
+            .unwrap();
 
         let last_modified_original = fs::metadata(&PathBuf::from_iter(vec![
             &test_working_dir_path,
@@ -73,4 +97,665 @@ mod tests {
         assert_eq!(last_modified_original, last_modified_after_create);
         //TODO assert contents unchanged
     }
+
+    #[actix_rt::test]
+    async fn create__given_path_to_missing_directory__returns_error() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+        let missing_dir_path =
+            PathBuf::from_iter(vec![&test_working_dir_path, &PathBuf::from(MISSING_DIR)]);
+
+        fs::remove_dir_all(&missing_dir_path).unwrap_or(());
+
+        let core = DataCore::create(&missing_dir_path, &NO_DB).await;
+
+        assert!(core.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn get_snapshots__given_stored_snapshots__returns_snapshots_in_date_order() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &ROUND_TRIP_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &ROUND_TRIP_DB)
+            .await
+            .unwrap();
+
+        let first = get_data_point("The Great Campaign", "2200.01.01", "Empire A");
+        let later = get_data_point("The Great Campaign", "2200.02.01", "Empire B");
+        let other = get_data_point("Another Campaign", "2200.01.01", "Empire C");
+        for data_point in [first.clone(), later.clone(), other.clone()] {
+            core.store_snapshot(&data_point, DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_snapshots().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &ROUND_TRIP_DB);
+
+        assert_eq!(actual, vec![first, other, later]);
+    }
+
+    #[actix_rt::test]
+    async fn get_snapshots__given_reopened_db__returns_previously_stored_snapshots() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &REOPENED_DB);
+
+        let expected = vec![get_data_point(
+            "The Great Campaign",
+            "2200.01.01",
+            "Empire A",
+        )];
+        {
+            let core = DataCore::create(&test_working_dir_path, &REOPENED_DB)
+                .await
+                .unwrap();
+            core.store_snapshot(&expected[0], DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let core = DataCore::create(&test_working_dir_path, &REOPENED_DB)
+            .await
+            .unwrap();
+        let actual = core.get_snapshots().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &REOPENED_DB);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[actix_rt::test]
+    async fn get_snapshots__given_snapshot_stored_before_dates__skips_undated_snapshot() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &LEGACY_DB);
+
+        create_sqlite_db(&test_working_dir_path, &LEGACY_DB)
+            .await
+            .unwrap();
+        let pool = SqlitePool::connect(&format!(
+            "sqlite:{}",
+            PathBuf::from_iter(vec![&test_working_dir_path, &PathBuf::from(LEGACY_DB)])
+                .to_str()
+                .unwrap()
+        ))
+        .await
+        .unwrap();
+        for statement in [
+            "CREATE TABLE campaigns (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL UNIQUE)",
+            "CREATE TABLE snapshots (id INTEGER PRIMARY KEY AUTOINCREMENT, campaign_id INTEGER NOT NULL)",
+            "INSERT INTO campaigns (name) VALUES ('Campaign A')",
+            "INSERT INTO snapshots (campaign_id) VALUES (1)",
+            "INSERT INTO snapshots (campaign_id) VALUES (1)",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+        pool.close().await;
+
+        let core = DataCore::create(&test_working_dir_path, &LEGACY_DB)
+            .await
+            .unwrap();
+        let dated = get_data_point("Campaign A", "2200.01.01", "Empire A");
+        core.store_snapshot(&dated, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        let actual = core.get_snapshots().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &LEGACY_DB);
+
+        assert_eq!(actual, vec![dated]);
+    }
+
+    #[actix_rt::test]
+    async fn get_campaign_names__given_stored_snapshots__returns_each_campaign_once() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &CAMPAIGNS_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &CAMPAIGNS_DB)
+            .await
+            .unwrap();
+        for data_point in [
+            get_data_point("Campaign A", "2200.01.01", "Empire A"),
+            get_data_point("Campaign B", "2200.01.01", "Empire B"),
+            get_data_point("Campaign A", "2200.02.01", "Empire A"),
+        ] {
+            core.store_snapshot(&data_point, DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_campaign_names().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &CAMPAIGNS_DB);
+
+        assert_eq!(
+            actual,
+            vec![String::from("Campaign A"), String::from("Campaign B")]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn get_current_campaign_name__given_new_campaign_at_earlier_date__returns_new_campaign() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &CURRENT_CAMPAIGN_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &CURRENT_CAMPAIGN_DB)
+            .await
+            .unwrap();
+        let empty = core.get_current_campaign_name().await.unwrap();
+        for data_point in [
+            get_data_point("Campaign A", "2350.01.01", "Empire A"),
+            get_data_point("Campaign B", "2200.01.01", "Empire B"),
+        ] {
+            core.store_snapshot(&data_point, DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_current_campaign_name().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &CURRENT_CAMPAIGN_DB);
+
+        assert_eq!(empty, None);
+        assert_eq!(actual, Some(String::from("Campaign B")));
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_series__given_snapshots_of_campaign__returns_empire_from_each_snapshot() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &SERIES_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &SERIES_DB)
+            .await
+            .unwrap();
+        let first = get_data_point("Campaign A", "2200.01.01", "Empire A");
+        let mut second = get_data_point("Campaign A", "2200.02.01", "Empire A");
+        second.empires[0].resources.energy = 0.5;
+        for data_point in [
+            first.clone(),
+            get_data_point("Campaign A", "2200.01.15", "Empire B"),
+            get_data_point("Campaign B", "2200.01.15", "Empire A"),
+            second.clone(),
+        ] {
+            core.store_snapshot(&data_point, DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let actual = core
+            .get_empire_series("Campaign A", "Empire A")
+            .await
+            .unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &SERIES_DB);
+
+        assert_eq!(
+            actual,
+            vec![first.empires[0].clone(), second.empires[0].clone()]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn delete_campaign__given_stored_campaigns__removes_only_that_campaign() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &DELETE_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &DELETE_DB)
+            .await
+            .unwrap();
+        let kept = get_data_point("Campaign B", "2200.01.01", "Empire B");
+        core.store_snapshot(
+            &get_data_point("Campaign A", "2200.01.01", "Empire A"),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+        core.store_snapshot(&kept, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        core.delete_campaign("Campaign A").await.unwrap();
+
+        let snapshots = core.get_snapshots().await.unwrap();
+        let campaign_names = core.get_campaign_names().await.unwrap();
+        let series = core
+            .get_empire_series("Campaign A", "Empire A")
+            .await
+            .unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &DELETE_DB);
+
+        assert_eq!(snapshots, vec![kept]);
+        assert_eq!(campaign_names, vec![String::from("Campaign B")]);
+        assert!(series.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn store_snapshot__given_duplicate_date_and_replace_policy__stores_newest_in_place() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &REPLACE_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &REPLACE_DB)
+            .await
+            .unwrap();
+        let later = get_data_point("Campaign A", "2200.02.01", "Empire A");
+        let replacement = get_data_point("Campaign A", "2200.01.01", "Empire B");
+        for data_point in [
+            get_data_point("Campaign A", "2200.01.01", "Empire A"),
+            later.clone(),
+            replacement.clone(),
+        ] {
+            core.store_snapshot(&data_point, DuplicatePolicy::Replace)
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_snapshots().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &REPLACE_DB);
+
+        assert_eq!(actual, vec![replacement, later]);
+    }
+
+    #[actix_rt::test]
+    async fn store_snapshot__given_duplicate_date_and_keep_first_policy__keeps_stored_snapshot() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &KEEP_FIRST_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &KEEP_FIRST_DB)
+            .await
+            .unwrap();
+        let first = get_data_point("Campaign A", "2200.01.01", "Empire A");
+        for data_point in [
+            first.clone(),
+            get_data_point("Campaign A", "2200.01.01", "Empire B"),
+        ] {
+            core.store_snapshot(&data_point, DuplicatePolicy::KeepFirst)
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_snapshots().await.unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &KEEP_FIRST_DB);
+
+        assert_eq!(actual, vec![first]);
+    }
+
+    #[actix_rt::test]
+    async fn get_snapshots__given_snapshots_stored_out_of_order__returns_snapshots_by_date() {
+        let test_working_dir_path = get_path(TEST_WORKING_DIRECTORY_SOURCE);
+
+        fs::create_dir_all(test_working_dir_path.clone()).unwrap();
+        cleanup_sqlite(&test_working_dir_path, &OUT_OF_ORDER_DB);
+
+        let core = DataCore::create(&test_working_dir_path, &OUT_OF_ORDER_DB)
+            .await
+            .unwrap();
+        let first = get_data_point("Campaign A", "2200.01.01", "Empire A");
+        let second = get_data_point("Campaign A", "2200.02.01", "Empire A");
+        let third = get_data_point("Campaign A", "2201.01.01", "Empire A");
+        for data_point in [third.clone(), first.clone(), second.clone()] {
+            core.store_snapshot(&data_point, DuplicatePolicy::default())
+                .await
+                .unwrap();
+        }
+
+        let actual = core.get_snapshots().await.unwrap();
+        let series = core
+            .get_empire_series("Campaign A", "Empire A")
+            .await
+            .unwrap();
+
+        cleanup_sqlite(&test_working_dir_path, &OUT_OF_ORDER_DB);
+
+        assert_eq!(actual, vec![first.clone(), second.clone(), third.clone()]);
+        assert_eq!(
+            series,
+            vec![
+                first.empires[0].clone(),
+                second.empires[0].clone(),
+                third.empires[0].clone()
+            ]
+        );
+    }
+
+    fn get_data_point(campaign_name: &str, date: &str, empire_name: &str) -> ModelDataPoint {
+        let mut income = HashMap::new();
+        income.insert(
+            ResourceClass::Energy,
+            vec![
+                (String::from("source_1"), 1000f64),
+                (String::from("source_2"), 1000f64),
+            ],
+        );
+        income.insert(
+            ResourceClass::Minerals,
+            vec![(String::from("source_2"), 300f64)],
+        );
+
+        let mut expense = HashMap::new();
+        expense.insert(
+            ResourceClass::Energy,
+            vec![(String::from("sink_1"), 500f64)],
+        );
+
+        ModelDataPoint {
+            campaign_name: String::from(campaign_name),
+            date: StellarisDate::from_str(date).unwrap(),
+            empires: vec![EmpireData {
+                name: String::from(empire_name),
+                budget: Budget {
+                    income: income.clone(),
+                    expense: expense.clone(),
+                    income_last_month: income,
+                    expense_last_month: expense,
+                    ..Budget::default()
+                },
+                resources: Resources {
+                    energy: 11484.2,
+                    minerals: 10302.2,
+                    sr_dark_matter: 8.0,
+                    ..Resources::default()
+                },
+                planets: vec![
+                    PlanetData {
+                        id: 8,
+                        name: String::from("Earth"),
+                        class: String::from("pc_continental"),
+                        size: 18,
+                        owner: 0,
+                        controller: 0,
+                        pops: 32,
+                        districts: vec![
+                            String::from("district_city"),
+                            String::from("district_farming"),
+                        ],
+                        buildings: vec![
+                            String::from("building_capital"),
+                            String::from("building_research_lab_1"),
+                        ],
+                        stability: 77.2672,
+                        amenities: 51.0,
+                        housing: 40.0,
+                        crime: 0.0,
+                    },
+                    PlanetData {
+                        id: 3,
+                        name: String::from("Mars"),
+                        class: String::from("pc_arid"),
+                        size: 12,
+                        owner: 0,
+                        controller: 1,
+                        ..PlanetData::default()
+                    },
+                ],
+                demographics: Demographics {
+                    species: HashMap::from([
+                        (String::from("Human"), 30),
+                        (String::from("Yaanari"), 2),
+                    ]),
+                    jobs: HashMap::from([
+                        (String::from("administrator"), 2),
+                        (String::from("farmer"), 30),
+                    ]),
+                    strata: HashMap::from([
+                        (String::from("ruler"), 2),
+                        (String::from("worker"), 30),
+                    ]),
+                    ethics: HashMap::from([(String::from("ethic_xenophile"), 32)]),
+                    happiness: 0.87567,
+                },
+                fleets: vec![
+                    FleetData {
+                        id: 300,
+                        name: String::from("Home Fleet"),
+                        ships: 5,
+                        classes: HashMap::from([
+                            (String::from("corvette"), 3),
+                            (String::from("destroyer"), 2),
+                        ]),
+                        military_power: 679.75854,
+                        system: 169,
+                    },
+                    FleetData {
+                        id: 1,
+                        name: String::from("Science Ship"),
+                        ships: 1,
+                        classes: HashMap::from([(String::from("science"), 1)]),
+                        military_power: 0.0,
+                        system: 12,
+                    },
+                ],
+                tech: TechStatus {
+                    researched: HashMap::from([
+                        (String::from("tech_lasers_1"), 1),
+                        (String::from("tech_repeatable_weapon_type_energy_damage"), 3),
+                    ]),
+                    physics: Some(ResearchData {
+                        technology: String::from("tech_lasers_2"),
+                        progress: 22.47168,
+                    }),
+                    society: None,
+                    engineering: Some(ResearchData {
+                        technology: String::from("tech_powered_exoskeletons"),
+                        progress: 23.52528,
+                    }),
+                },
+                leaders: vec![
+                    LeaderData {
+                        id: 0,
+                        name: String::from("Dolores Muwanga"),
+                        class: String::from("ruler"),
+                        level: 2,
+                        traits: vec![
+                            String::from("trait_ruler_expansionist"),
+                            String::from("trait_ruler_space_miner"),
+                        ],
+                        age: 28,
+                        assignment: String::from("planet"),
+                        location: 3,
+                        owner: 0,
+                    },
+                    LeaderData {
+                        id: 1,
+                        name: String::from("Qiang Zhu"),
+                        class: String::from("governor"),
+                        level: 1,
+                        traits: vec![String::from("leader_trait_architectural_interest")],
+                        age: 42,
+                        assignment: String::from("sector"),
+                        location: 0,
+                        owner: 0,
+                    },
+                ],
+                military_power: 107.5,
+                economy_power: 1.0,
+                tech_power: 240.0,
+                victory_score: 185.0,
+                victory_rank: 6,
+                empire_size: 33.0,
+                fleet_size: 3,
+                starbase_capacity: 3,
+                trade_value: 35.55,
+                identity: EmpireIdentity {
+                    government: String::from("gov_representative_democracy"),
+                    authority: String::from("auth_democratic"),
+                    civics: vec![
+                        String::from("civic_beacon_of_liberty"),
+                        String::from("civic_idealistic_foundation"),
+                    ],
+                    ethics: vec![
+                        String::from("ethic_fanatic_egalitarian"),
+                        String::from("ethic_xenophile"),
+                    ],
+                    origin: String::from("origin_default"),
+                    policies: vec![
+                        PolicyData {
+                            policy: String::from("diplomatic_stance"),
+                            selected: String::from("diplo_stance_expansionist"),
+                            date: None,
+                        },
+                        PolicyData {
+                            policy: String::from("robot_pop_policy"),
+                            selected: String::from("robot_pops_outlawed"),
+                            date: Some(StellarisDate::from_str("2200.01.01").unwrap()),
+                        },
+                    ],
+                    ruler: Some(0),
+                },
+            }],
+            wars: vec![WarData {
+                id: 0,
+                name: String::from("Sol-Yaanari War"),
+                start_date: StellarisDate::from_str("2230.05.01").unwrap(),
+                attackers: vec![String::from(empire_name)],
+                defenders: vec![
+                    String::from("Yaanari Imperium"),
+                    String::from("Scyldari Confederacy"),
+                ],
+                attacker_war_goal: String::from("wg_conquest"),
+                defender_war_goal: None,
+                attacker_exhaustion: 0.12,
+                defender_exhaustion: 0.34,
+            }],
+            truces: vec![TruceData {
+                id: 3,
+                name: String::from("Truce of Sol"),
+                start_date: StellarisDate::from_str("2205.01.01").unwrap(),
+                truce_type: Some(String::from("war")),
+                countries: vec![String::from(empire_name), String::from("Other Empire")],
+            }],
+            federations: vec![FederationData {
+                id: 0,
+                name: String::from("Sol Federation"),
+                federation_type: String::from("research_federation"),
+                level: 2,
+                cohesion: 46.5,
+                leader: Some(String::from(empire_name)),
+                members: vec![String::from(empire_name), String::from("Yaanari Imperium")],
+            }],
+            galactic_community: GalacticCommunity {
+                members: vec![
+                    String::from(empire_name),
+                    String::from("Yaanari Imperium"),
+                    String::from("Scyldari Confederacy"),
+                ],
+                council: vec![String::from(empire_name)],
+                resolutions: vec![String::from("resolution_greatergood_balance_in_the_middle")],
+                voting: Some(String::from("resolution_mutualdefense_defense_pact")),
+            },
+            relations: vec![
+                RelationData {
+                    owner: String::from(empire_name),
+                    country: String::from("Yaanari Imperium"),
+                    opinion: 120,
+                    trust: 25.5,
+                    treaties: vec![
+                        String::from("research_agreement"),
+                        String::from("defensive_pact"),
+                    ],
+                    rival: false,
+                    borders: true,
+                    contact: true,
+                },
+                RelationData {
+                    owner: String::from(empire_name),
+                    country: String::from("Scyldari Confederacy"),
+                    opinion: -80,
+                    rival: true,
+                    contact: true,
+                    ..RelationData::default()
+                },
+            ],
+            systems: vec![
+                SystemData {
+                    id: 0,
+                    name: String::from("Sol"),
+                    x: 81.72,
+                    y: 87.22,
+                    star_class: String::from("sc_g"),
+                    hyperlanes: vec![1, 2],
+                    owner: Some(String::from(empire_name)),
+                    trade: TradeHubData {
+                        collected: 28.85,
+                        collected_from: vec![0, 1],
+                        throughput: 12.5,
+                        piracy: 1.5,
+                    },
+                },
+                SystemData {
+                    id: 1,
+                    name: String::from("Alpha Centauri"),
+                    x: 75.0,
+                    y: 90.5,
+                    star_class: String::from("sc_binary_1"),
+                    hyperlanes: vec![0],
+                    owner: None,
+                    trade: TradeHubData::default(),
+                },
+            ],
+            market: MarketData {
+                resources: vec![
+                    MarketResourceData {
+                        resource: ResourceClass::Minerals,
+                        fluctuation: -12.5,
+                        price: 0.875,
+                    },
+                    MarketResourceData {
+                        resource: ResourceClass::Alloys,
+                        fluctuation: 20.0,
+                        price: 4.8,
+                    },
+                ],
+                trades: vec![MarketTradeData {
+                    country: String::from(empire_name),
+                    resource: ResourceClass::Minerals,
+                    bought: 0.0,
+                    sold: 1500.0,
+                }],
+            },
+            trade_routes: vec![TradeRouteData {
+                id: 0,
+                owner: String::from(empire_name),
+                from: 1,
+                to: 0,
+                delivered: 11.0,
+                path: vec![1, 0],
+            }],
+            megastructures: vec![
+                MegastructureData {
+                    id: 0,
+                    megastructure_type: String::from("dyson_sphere_2"),
+                    owner: Some(String::from(empire_name)),
+                    system: 0,
+                },
+                MegastructureData {
+                    id: 1,
+                    megastructure_type: String::from("gateway_ruined"),
+                    owner: None,
+                    system: 1,
+                },
+            ],
+        }
+    }
 }
@@ -1,76 +1,278 @@
 use std::{
-    sync::{mpsc::Receiver, Arc, Mutex},
+    collections::HashMap,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
     thread,
 };
 
+use actix_rt::System;
 use anyhow::Result;
+use data_core::{DataCoreBackend, DuplicatePolicy, EmpireData, ModelDataPoint, StellarisDate};
+use stellarust::dto::CampaignUpdateDto;
 
-use super::data::ModelDataPoint;
-
-pub struct ModelCustodian {
-    history: Arc<Mutex<Vec<ModelDataPoint>>>,
+pub struct ModelCustodian<B: DataCoreBackend> {
+    history: Arc<Mutex<History>>,
+    data_core: Arc<B>,
+    policy: DuplicatePolicy,
+    subscribers: Arc<Mutex<Vec<Sender<CampaignUpdateDto>>>>,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum CustodianMsg {
-    Data(ModelDataPoint),
+    Data(Box<ModelDataPoint>),
     Exit,
 }
 
-impl ModelCustodian {
-    pub fn create(receiver: Receiver<CustodianMsg>) -> Self {
+/// Data points grouped by campaign, each campaign sorted by date. The current campaign is the one
+/// that most recently received a data point, regardless of its in-game date.
+#[derive(Default)]
+struct History {
+    campaigns: HashMap<String, Vec<ModelDataPoint>>,
+    current_campaign: Option<String>,
+}
+
+impl<B: DataCoreBackend> ModelCustodian<B> {
+    pub async fn create(
+        receiver: Receiver<CustodianMsg>,
+        data_core: B,
+        policy: DuplicatePolicy,
+    ) -> Result<Self> {
+        let history = History::new(
+            data_core.get_snapshots().await?,
+            data_core.get_current_campaign_name().await?,
+        );
         let me = ModelCustodian {
-            history: Arc::new(Mutex::new(vec![])),
+            history: Arc::new(Mutex::new(history)),
+            data_core: Arc::new(data_core),
+            policy,
+            subscribers: Arc::new(Mutex::new(vec![])),
         };
 
         me.start(receiver);
-        me
+        Ok(me)
     }
 
     fn start(&self, receiver: Receiver<CustodianMsg>) {
         let history = self.history.clone();
-        thread::spawn(move || loop {
-            match receiver.recv() {
-                Ok(data) => match data {
-                    CustodianMsg::Data(i) => {
-                        history.lock().unwrap().push(i);
-                        log::info!("Received New Data");
-                    }
+        let data_core = self.data_core.clone();
+        let policy = self.policy;
+        let subscribers = self.subscribers.clone();
+        thread::spawn(move || {
+            let system = System::new();
+            while let Ok(message) = receiver.recv() {
+                let data_point = match message {
+                    CustodianMsg::Data(data_point) => data_point,
                     CustodianMsg::Exit => break,
-                },
-                _err => break,
-            };
+                };
+                if history.lock().unwrap().is_skipped(&data_point, policy) {
+                    log::info!("Skipped Duplicate Data {}", data_point.date);
+                    continue;
+                }
+                log::info!("Received New Data");
+                if let Err(e) = system.block_on(data_core.store_snapshot(&data_point, policy)) {
+                    log::error!("Could not persist data point {}: {}", data_point.date, e);
+                    continue;
+                }
+                let changed_empires = {
+                    let mut history = history.lock().unwrap();
+                    history.add(&data_point);
+                    history.get_changed_empires(&data_point)
+                };
+                notify_subscribers(
+                    &subscribers,
+                    CampaignUpdateDto {
+                        campaign: data_point.campaign_name.clone(),
+                        date: data_point.date.to_string(),
+                        empires: changed_empires,
+                    },
+                );
+            }
         });
     }
 
+    pub fn subscribe(&self) -> Receiver<CampaignUpdateDto> {
+        let (sender, receiver) = channel();
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
     pub async fn get_empire_names(&self) -> Result<Vec<String>> {
-        match self.history.lock().unwrap().last() {
-            Some(data_point) => {
-                let empires = &data_point.empires;
-                Ok(empires
-                    .into_iter()
-                    .map(|empire| empire.name.clone())
-                    .collect())
-            }
+        match self.history.lock().unwrap().get_current_campaign().last() {
+            Some(data_point) => Ok(data_point
+                .empires
+                .iter()
+                .map(|empire| empire.name.clone())
+                .collect()),
             None => Ok(vec![]),
         }
     }
+
+    pub async fn get_empire_history(
+        &self,
+        empire_name: &str,
+        from: Option<StellarisDate>,
+        to: Option<StellarisDate>,
+    ) -> Result<Vec<(StellarisDate, EmpireData)>> {
+        Ok(self
+            .history
+            .lock()
+            .unwrap()
+            .get_current_campaign()
+            .iter()
+            .filter(|data_point| from.iter().all(|from| data_point.date >= *from))
+            .filter(|data_point| to.iter().all(|to| data_point.date <= *to))
+            .filter_map(|data_point| {
+                data_point
+                    .empires
+                    .iter()
+                    .find(|empire| empire.name == empire_name)
+                    .map(|empire| (data_point.date, empire.clone()))
+            })
+            .collect())
+    }
+
+    pub async fn get_campaign_history(&self, campaign_name: &str) -> Result<Vec<ModelDataPoint>> {
+        Ok(self
+            .history
+            .lock()
+            .unwrap()
+            .get_campaign(campaign_name)
+            .to_vec())
+    }
+
+    pub async fn get_current_campaign_history(&self) -> Result<Vec<ModelDataPoint>> {
+        Ok(self.history.lock().unwrap().get_current_campaign().to_vec())
+    }
+
+    pub async fn get_campaign_names(&self) -> Result<Vec<String>> {
+        self.data_core.get_campaign_names().await
+    }
+
+    pub async fn delete_campaign(&self, campaign_name: &str) -> Result<()> {
+        self.data_core.delete_campaign(campaign_name).await?;
+        self.history.lock().unwrap().remove_campaign(campaign_name);
+        Ok(())
+    }
+}
+
+impl History {
+    fn new(snapshots: Vec<ModelDataPoint>, current_campaign: Option<String>) -> Self {
+        let mut history = History {
+            current_campaign,
+            ..History::default()
+        };
+        for data_point in snapshots {
+            let campaign = history
+                .campaigns
+                .entry(data_point.campaign_name.clone())
+                .or_default();
+            let index = campaign.partition_point(|stored| stored.date <= data_point.date);
+            campaign.insert(index, data_point);
+        }
+        history
+    }
+
+    fn get_campaign(&self, campaign_name: &str) -> &[ModelDataPoint] {
+        self.campaigns
+            .get(campaign_name)
+            .map_or(&[], |campaign| campaign.as_slice())
+    }
+
+    fn get_current_campaign(&self) -> &[ModelDataPoint] {
+        match &self.current_campaign {
+            Some(campaign_name) => self.get_campaign(campaign_name),
+            None => &[],
+        }
+    }
+
+    fn is_skipped(&self, data_point: &ModelDataPoint, policy: DuplicatePolicy) -> bool {
+        policy == DuplicatePolicy::KeepFirst
+            && self
+                .get_campaign(&data_point.campaign_name)
+                .iter()
+                .any(|stored| stored.date == data_point.date)
+    }
+
+    /// Adds or replaces the data point; callers check `is_skipped` first.
+    fn add(&mut self, data_point: &ModelDataPoint) {
+        let campaign = self
+            .campaigns
+            .entry(data_point.campaign_name.clone())
+            .or_default();
+        match campaign
+            .iter()
+            .position(|stored| stored.date == data_point.date)
+        {
+            Some(index) => campaign[index] = data_point.clone(),
+            None => {
+                let index = campaign.partition_point(|stored| stored.date <= data_point.date);
+                campaign.insert(index, data_point.clone());
+            }
+        }
+        self.current_campaign = Some(data_point.campaign_name.clone());
+    }
+
+    fn remove_campaign(&mut self, campaign_name: &str) {
+        self.campaigns.remove(campaign_name);
+        if self.current_campaign.as_deref() == Some(campaign_name) {
+            self.current_campaign = None;
+        }
+    }
+
+    fn get_changed_empires(&self, data_point: &ModelDataPoint) -> Vec<String> {
+        let previous = self
+            .get_campaign(&data_point.campaign_name)
+            .iter()
+            .rev()
+            .find(|stored| stored.date < data_point.date);
+        data_point
+            .empires
+            .iter()
+            .filter(|empire| match previous {
+                Some(previous) => !previous.empires.contains(empire),
+                None => true,
+            })
+            .map(|empire| empire.name.clone())
+            .collect()
+    }
+}
+
+fn notify_subscribers(
+    subscribers: &Mutex<Vec<Sender<CampaignUpdateDto>>>,
+    update: CampaignUpdateDto,
+) {
+    subscribers
+        .lock()
+        .unwrap()
+        .retain(|subscriber| subscriber.send(update.clone()).is_ok());
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::{Budget, CustodianMsg, EmpireData, ModelCustodian, ModelDataPoint, Resources};
-    use std::{sync::mpsc::channel, thread, time::Duration};
+    use crate::{
+        CustodianMsg, EmpireData, GalacticCommunity, MarketData, ModelCustodian, ModelDataPoint,
+    };
+    use data_core::{DataCoreBackend, DuplicatePolicy, StellarisDate};
+    use data_core_mock::MockDataCore;
+    use std::{str::FromStr, sync::mpsc::channel, thread, time::Duration};
+    use stellarust::dto::CampaignUpdateDto;
 
     const EMPIRE_NAME: &str = "EMPIRE_NAME";
+    const CAMPAIGN_NAME: &str = "The Great Campaign";
+    const DATE: &str = "2200.01.01";
 
     #[actix_rt::test]
     async fn get_empire_names__given_no_data__returns_empty_list() {
         let (sender, receiver) = channel();
         sender.send(CustodianMsg::Exit).unwrap();
 
-        let model = ModelCustodian::create(receiver);
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
 
         thread::sleep(Duration::from_millis(5));
 
@@ -82,9 +284,14 @@ mod tests {
     #[actix_rt::test]
     async fn get_empire_names__given_single_data__returns_name_from_data() {
         let (sender, receiver) = channel();
-        sender.send(get_custodian_message(EMPIRE_NAME)).unwrap();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, DATE))
+            .unwrap();
         sender.send(CustodianMsg::Exit).unwrap();
-        let model = ModelCustodian::create(receiver);
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
 
         thread::sleep(Duration::from_millis(5));
 
@@ -96,12 +303,23 @@ mod tests {
     #[actix_rt::test]
     async fn get_empire_names__given_series_of_data__returns_list_of_names_from_last_in_series() {
         let (sender, receiver) = channel();
-        sender.send(get_custodian_message("0")).unwrap();
-        sender.send(get_custodian_message("2")).unwrap();
-        sender.send(get_custodian_message("3")).unwrap();
-        sender.send(get_custodian_message(EMPIRE_NAME)).unwrap();
+        sender
+            .send(get_custodian_message("0", "2200.01.01"))
+            .unwrap();
+        sender
+            .send(get_custodian_message("2", "2200.02.01"))
+            .unwrap();
+        sender
+            .send(get_custodian_message("3", "2200.03.01"))
+            .unwrap();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, "2200.04.01"))
+            .unwrap();
         sender.send(CustodianMsg::Exit).unwrap();
-        let model = ModelCustodian::create(receiver);
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
 
         thread::sleep(Duration::from_millis(5));
 
@@ -110,14 +328,377 @@ mod tests {
         assert_eq!(actual, vec![String::from(EMPIRE_NAME),]);
     }
 
-    fn get_custodian_message(empire_name: &str) -> CustodianMsg {
-        CustodianMsg::Data(ModelDataPoint {
-            campaign_name: String::from("The Great Campaign"),
+    #[actix_rt::test]
+    async fn get_empire_names__given_data_from_previous_run__returns_name_from_stored_data() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let data_core = MockDataCore::with_snapshots(vec![get_data_point(EMPIRE_NAME, DATE)]);
+
+        let model = ModelCustodian::create(receiver, data_core, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        let actual = model.get_empire_names().await.unwrap();
+
+        assert_eq!(actual, vec![String::from(EMPIRE_NAME),]);
+    }
+
+    #[actix_rt::test]
+    async fn create__given_data__data_is_stored_in_data_core() {
+        let (sender, receiver) = channel();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, DATE))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.data_core.get_snapshots().await.unwrap();
+
+        assert_eq!(actual, vec![get_data_point(EMPIRE_NAME, DATE)]);
+    }
+
+    #[actix_rt::test]
+    async fn create__given_data_that_fails_to_persist__keeps_history_and_does_not_notify() {
+        let (sender, receiver) = channel();
+        let model = ModelCustodian::create(
+            receiver,
+            MockDataCore::with_failing_stores(),
+            DuplicatePolicy::default(),
+        )
+        .await
+        .unwrap();
+        let updates = model.subscribe();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, DATE))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        assert!(updates.try_recv().is_err());
+        assert!(model.get_empire_names().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn delete_campaign__given_stored_campaign__removes_campaign_from_history_and_data_core() {
+        let (sender, receiver) = channel();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let data_core = MockDataCore::with_snapshots(vec![get_data_point(EMPIRE_NAME, DATE)]);
+        let model = ModelCustodian::create(receiver, data_core, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        model.delete_campaign(CAMPAIGN_NAME).await.unwrap();
+
+        assert!(model.get_empire_names().await.unwrap().is_empty());
+        assert!(model.get_campaign_names().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_names__given_duplicate_date_and_replace_policy__returns_names_from_newest()
+    {
+        let (sender, receiver) = channel();
+        sender.send(get_custodian_message("FIRST", DATE)).unwrap();
+        sender.send(get_custodian_message("SECOND", DATE)).unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model = ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::Replace)
+            .await
+            .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.get_empire_names().await.unwrap();
+        let stored = model.data_core.get_snapshots().await.unwrap();
+
+        assert_eq!(actual, vec![String::from("SECOND")]);
+        assert_eq!(stored, vec![get_data_point("SECOND", DATE)]);
+        assert_eq!(get_history_len(&model), 1);
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_names__given_duplicate_date_and_keep_first_policy__returns_names_from_first(
+    ) {
+        let (sender, receiver) = channel();
+        sender.send(get_custodian_message("FIRST", DATE)).unwrap();
+        sender.send(get_custodian_message("SECOND", DATE)).unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::KeepFirst)
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.get_empire_names().await.unwrap();
+        let stored = model.data_core.get_snapshots().await.unwrap();
+
+        assert_eq!(actual, vec![String::from("FIRST")]);
+        assert_eq!(stored, vec![get_data_point("FIRST", DATE)]);
+        assert_eq!(get_history_len(&model), 1);
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_names__given_same_date_in_different_campaigns__keeps_both() {
+        let (sender, receiver) = channel();
+        let mut other_campaign = get_data_point("OTHER", DATE);
+        other_campaign.campaign_name = String::from("Another Campaign");
+        sender.send(get_custodian_message("FIRST", DATE)).unwrap();
+        sender
+            .send(CustodianMsg::Data(Box::new(other_campaign)))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::KeepFirst)
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.get_empire_names().await.unwrap();
+
+        assert_eq!(actual, vec![String::from("OTHER")]);
+        assert_eq!(get_history_len(&model), 2);
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_names__given_data_out_of_order__returns_names_from_latest_date() {
+        let (sender, receiver) = channel();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, "2200.03.01"))
+            .unwrap();
+        sender
+            .send(get_custodian_message("EARLIEST", "2200.01.01"))
+            .unwrap();
+        sender
+            .send(get_custodian_message("EARLIER", "2200.02.01"))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.get_empire_names().await.unwrap();
+        let dates: Vec<String> = model
+            .get_current_campaign_history()
+            .await
+            .unwrap()
+            .iter()
+            .map(|data_point| data_point.date.to_string())
+            .collect();
+
+        assert_eq!(actual, vec![String::from(EMPIRE_NAME),]);
+        assert_eq!(dates, vec!["2200.01.01", "2200.02.01", "2200.03.01"]);
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_names__given_new_campaign_after_later_campaign__returns_new_campaign() {
+        let (sender, receiver) = channel();
+        let mut new_campaign = get_data_point("NEW", "2200.01.01");
+        new_campaign.campaign_name = String::from("Another Campaign");
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, "2350.06.01"))
+            .unwrap();
+        sender
+            .send(CustodianMsg::Data(Box::new(new_campaign.clone())))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+
+        thread::sleep(Duration::from_millis(5));
+
+        let actual = model.get_empire_names().await.unwrap();
+        let current = model.get_current_campaign_history().await.unwrap();
+
+        assert_eq!(actual, vec![String::from("NEW")]);
+        assert_eq!(current, vec![new_campaign]);
+    }
+
+    #[actix_rt::test]
+    async fn subscribe__given_other_campaign_at_earlier_date__compares_within_own_campaign() {
+        let (sender, receiver) = channel();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+        let updates = model.subscribe();
+
+        let mut other = get_data_point(EMPIRE_NAME, "2200.01.01");
+        other.campaign_name = String::from("Another Campaign");
+        sender.send(CustodianMsg::Data(Box::new(other))).unwrap();
+        sender
+            .send(get_custodian_message(EMPIRE_NAME, "2200.02.01"))
+            .unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+
+        let actual: Vec<CampaignUpdateDto> = updates.iter().take(2).collect();
+
+        assert_eq!(actual[1].empires, vec![String::from(EMPIRE_NAME)]);
+    }
+
+    #[actix_rt::test]
+    async fn get_empire_history__given_date_range__returns_empire_from_current_campaign_in_range() {
+        let (sender, receiver) = channel();
+        let mut other_campaign = get_data_point(EMPIRE_NAME, "2200.02.01");
+        other_campaign.campaign_name = String::from("Another Campaign");
+        let data_core = MockDataCore::with_snapshots(vec![
+            other_campaign,
+            get_data_point(EMPIRE_NAME, "2200.01.01"),
+            get_data_point("OTHER", "2200.02.01"),
+            get_data_point(EMPIRE_NAME, "2200.03.01"),
+            get_data_point(EMPIRE_NAME, "2200.04.01"),
+        ]);
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model = ModelCustodian::create(receiver, data_core, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        let actual = model
+            .get_empire_history(
+                EMPIRE_NAME,
+                Some(StellarisDate::from_str("2200.02.01").unwrap()),
+                Some(StellarisDate::from_str("2200.03.01").unwrap()),
+            )
+            .await
+            .unwrap();
+
+        let expected = get_data_point(EMPIRE_NAME, "2200.03.01");
+        assert_eq!(actual, vec![(expected.date, expected.empires[0].clone())]);
+    }
+
+    #[actix_rt::test]
+    async fn get_campaign_history__given_several_campaigns__returns_data_points_of_campaign() {
+        let (sender, receiver) = channel();
+        let mut other_campaign = get_data_point(EMPIRE_NAME, "2200.02.01");
+        other_campaign.campaign_name = String::from("Another Campaign");
+        let data_core = MockDataCore::with_snapshots(vec![
+            get_data_point(EMPIRE_NAME, "2200.01.01"),
+            other_campaign,
+            get_data_point(EMPIRE_NAME, "2200.03.01"),
+        ]);
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model = ModelCustodian::create(receiver, data_core, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        let actual = model.get_campaign_history(CAMPAIGN_NAME).await.unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                get_data_point(EMPIRE_NAME, "2200.01.01"),
+                get_data_point(EMPIRE_NAME, "2200.03.01")
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn get_current_campaign_history__given_several_campaigns__returns_latest_campaign() {
+        let (sender, receiver) = channel();
+        let mut other_campaign = get_data_point(EMPIRE_NAME, "2200.01.01");
+        other_campaign.campaign_name = String::from("Another Campaign");
+        let data_core = MockDataCore::with_snapshots(vec![
+            other_campaign,
+            get_data_point(EMPIRE_NAME, "2200.02.01"),
+            get_data_point(EMPIRE_NAME, "2200.03.01"),
+        ]);
+        sender.send(CustodianMsg::Exit).unwrap();
+        let model = ModelCustodian::create(receiver, data_core, DuplicatePolicy::default())
+            .await
+            .unwrap();
+
+        let actual = model.get_current_campaign_history().await.unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                get_data_point(EMPIRE_NAME, "2200.02.01"),
+                get_data_point(EMPIRE_NAME, "2200.03.01")
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn subscribe__given_new_data__receives_update_with_changed_empires() {
+        let (sender, receiver) = channel();
+        let model =
+            ModelCustodian::create(receiver, MockDataCore::new(), DuplicatePolicy::default())
+                .await
+                .unwrap();
+        let updates = model.subscribe();
+
+        let mut first = get_data_point(EMPIRE_NAME, "2200.01.01");
+        first
+            .empires
+            .push(get_data_point("OTHER", DATE).empires.remove(0));
+        let mut second = first.clone();
+        second.date = StellarisDate::from_str("2200.02.01").unwrap();
+        second.empires[1].resources.energy = 100f64;
+        sender.send(CustodianMsg::Data(Box::new(first))).unwrap();
+        sender.send(CustodianMsg::Data(Box::new(second))).unwrap();
+        sender.send(CustodianMsg::Exit).unwrap();
+
+        let actual: Vec<CampaignUpdateDto> = updates.iter().take(2).collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                CampaignUpdateDto {
+                    campaign: String::from(CAMPAIGN_NAME),
+                    date: String::from("2200.01.01"),
+                    empires: vec![String::from(EMPIRE_NAME), String::from("OTHER")],
+                },
+                CampaignUpdateDto {
+                    campaign: String::from(CAMPAIGN_NAME),
+                    date: String::from("2200.02.01"),
+                    empires: vec![String::from("OTHER")],
+                },
+            ]
+        );
+    }
+
+    fn get_history_len(model: &ModelCustodian<MockDataCore>) -> usize {
+        model
+            .history
+            .lock()
+            .unwrap()
+            .campaigns
+            .values()
+            .map(Vec::len)
+            .sum()
+    }
+
+    fn get_custodian_message(empire_name: &str, date: &str) -> CustodianMsg {
+        CustodianMsg::Data(Box::new(get_data_point(empire_name, date)))
+    }
+
+    fn get_data_point(empire_name: &str, date: &str) -> ModelDataPoint {
+        ModelDataPoint {
+            campaign_name: String::from(CAMPAIGN_NAME),
+            date: StellarisDate::from_str(date).unwrap(),
             empires: vec![EmpireData {
                 name: String::from(empire_name),
-                resources: Resources::default(),
-                budget: Budget::default(),
+                ..EmpireData::default()
             }],
-        })
+            wars: vec![],
+            truces: vec![],
+            federations: vec![],
+            galactic_community: GalacticCommunity::default(),
+            relations: vec![],
+            systems: vec![],
+            market: MarketData::default(),
+            trade_routes: vec![],
+            megastructures: vec![],
+        }
     }
 }
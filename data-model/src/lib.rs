@@ -1,5 +1,9 @@
 mod custodian;
-mod data;
 
 pub use custodian::{CustodianMsg, ModelCustodian};
-pub use data::{Budget, EmpireData, ModelDataPoint, Resources};
+pub use data_core::{
+    Budget, Demographics, EmpireData, EmpireIdentity, FederationData, FleetData, GalacticCommunity,
+    LeaderData, MarketData, MarketResourceData, MarketTradeData, MegastructureData, ModelDataPoint,
+    PlanetData, PolicyData, RelationData, ResearchData, Resources, StellarisDate, SystemData,
+    TechStatus, TradeHubData, TradeRouteData, TruceData, WarData,
+};
@@ -4,6 +4,7 @@ pub(crate) mod app;
 pub(crate) mod fetch;
 pub(crate) mod pages;
 pub(crate) mod route;
+pub(crate) mod updates;
 
 use wasm_bindgen::prelude::*;
 
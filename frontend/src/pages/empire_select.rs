@@ -1,4 +1,7 @@
-use crate::fetch::{Fetch, FetchState};
+use crate::{
+    fetch::{Fetch, FetchState},
+    updates::{UpdateListener, UPDATES_URL},
+};
 use yew::{html, Component, Html};
 
 type EmpireSelectData = Vec<String>;
@@ -11,6 +14,7 @@ pub struct EmpireSelectState {
 
 pub struct EmpireSelect {
     state: EmpireSelectState,
+    _updates: Option<UpdateListener>,
 }
 
 pub enum Msg {
@@ -24,7 +28,17 @@ impl Component for EmpireSelect {
     type Properties = ();
 
     fn create(ctx: &yew::Context<Self>) -> Self {
-        let home = EmpireSelect::new();
+        let updates = match UpdateListener::listen(
+            UPDATES_URL,
+            ctx.link().callback(|_| Msg::GetEmpireList),
+        ) {
+            Ok(listener) => Some(listener),
+            Err(e) => {
+                log::error!("Could not listen for updates: {:?}", e);
+                None
+            }
+        };
+        let home = EmpireSelect::new(updates);
         ctx.link().callback(|_: ()| Msg::GetEmpireList).emit(());
         home
     }
@@ -57,11 +71,12 @@ impl Component for EmpireSelect {
 impl Fetch<EmpireSelectData> for EmpireSelect {}
 
 impl EmpireSelect {
-    fn new() -> Self {
+    fn new(updates: Option<UpdateListener>) -> Self {
         Self {
             state: EmpireSelectState {
                 empire_list: FetchState::NotFetching,
             },
+            _updates: updates,
         }
     }
     fn view_home(state: &FetchState<EmpireSelectData>) -> Html {
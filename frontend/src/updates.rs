@@ -0,0 +1,42 @@
+use stellarust::dto::CampaignUpdateDto;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{EventSource, MessageEvent};
+use yew::Callback;
+
+pub const UPDATES_URL: &str = "http://localhost:8000/updates";
+
+/// Listens for campaign updates while held. Pages refresh by keeping their own listener;
+/// `EmpireSelect`, the only routed page so far, is the one that does.
+pub struct UpdateListener {
+    event_source: EventSource,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl UpdateListener {
+    pub fn listen(url: &str, callback: Callback<CampaignUpdateDto>) -> Result<Self, JsValue> {
+        let event_source = EventSource::new(url)?;
+
+        let on_message = Closure::wrap(Box::new(move |event: MessageEvent| {
+            let text = match event.data().as_string() {
+                Some(text) => text,
+                None => return,
+            };
+            match serde_json::from_str::<CampaignUpdateDto>(&text) {
+                Ok(update) => callback.emit(update),
+                Err(e) => log::error!("Could not deserialize update '{}': {}", text, e),
+            }
+        }) as Box<dyn FnMut(MessageEvent)>);
+        event_source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            event_source,
+            _on_message: on_message,
+        })
+    }
+}
+
+impl Drop for UpdateListener {
+    fn drop(&mut self) {
+        self.event_source.close();
+    }
+}
@@ -1,3 +1,9 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+#[derive(
+    Deserialize, Serialize, Debug, Display, EnumIter, EnumString, PartialEq, Eq, Hash, Clone,
+)]
 pub enum BudgetComponent {
     Income,
     Expenses,
@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{BudgetComponent, ResourceClass};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct BudgetDto {
+    pub empire: String,
+    pub date: String,
+    pub resources: HashMap<ResourceClass, ResourceBudgetDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone, Default)]
+pub struct ResourceBudgetDto {
+    pub contributors: HashMap<BudgetComponent, Vec<(String, f64)>>,
+    pub totals: HashMap<BudgetComponent, f64>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct CampaignUpdateDto {
+    pub campaign: String,
+    pub date: String,
+    pub empires: Vec<String>,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use super::PlanetDto;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct ColoniesDto {
+    pub empire: String,
+    pub snapshots: Vec<ColonySnapshotDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct ColonySnapshotDto {
+    pub date: String,
+    pub planets: Vec<PlanetDto>,
+}
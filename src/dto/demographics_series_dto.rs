@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct DemographicsSeriesDto {
+    pub empire: String,
+    pub dates: Vec<String>,
+    pub species: HashMap<String, Vec<i64>>,
+    pub jobs: HashMap<String, Vec<i64>>,
+    pub strata: HashMap<String, Vec<i64>>,
+    pub ethics: HashMap<String, Vec<i64>>,
+    pub happiness: Vec<f64>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct DiplomacyDto {
+    pub campaign: String,
+    pub snapshots: Vec<DiplomacySnapshotDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct DiplomacySnapshotDto {
+    pub date: String,
+    pub federations: Vec<FederationDto>,
+    pub galactic_community: GalacticCommunityDto,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct FederationDto {
+    pub name: String,
+    pub federation_type: String,
+    pub level: i64,
+    pub cohesion: f64,
+    pub leader: Option<String>,
+    pub members: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct GalacticCommunityDto {
+    pub members: Vec<String>,
+    pub council: Vec<String>,
+    pub resolutions: Vec<String>,
+    pub voting: Option<String>,
+}
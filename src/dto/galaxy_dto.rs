@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct GalaxyDto {
+    pub date: String,
+    pub systems: Vec<SystemDto>,
+    pub hyperlanes: Vec<HyperlaneDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct SystemDto {
+    pub id: i64,
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    pub star_class: String,
+    pub owner: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct HyperlaneDto {
+    pub from: i64,
+    pub to: i64,
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct IdentityDto {
+    pub empire: String,
+    pub snapshots: Vec<IdentitySnapshotDto>,
+    pub changes: Vec<IdentityChangeDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct IdentitySnapshotDto {
+    pub date: String,
+    pub government: String,
+    pub authority: String,
+    pub civics: Vec<String>,
+    pub ethics: Vec<String>,
+    pub origin: String,
+    pub policies: Vec<PolicyDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct PolicyDto {
+    pub policy: String,
+    pub selected: String,
+    pub adopted: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct IdentityChangeDto {
+    pub date: String,
+    pub kind: IdentityChangeKind,
+    pub policy: Option<String>,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IdentityChangeKind {
+    Government,
+    Authority,
+    Origin,
+    Civic,
+    Ethic,
+    Policy,
+}
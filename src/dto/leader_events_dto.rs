@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LeaderEventsDto {
+    pub empire: String,
+    pub events: Vec<LeaderEventDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LeaderEventDto {
+    pub date: String,
+    pub kind: LeaderEventKind,
+    pub id: i64,
+    pub name: String,
+    pub class: String,
+    pub level: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LeaderEventKind {
+    Recruited,
+    LevelUp,
+    /// The leader is gone from the save, whether through death, dismissal or exile.
+    Removed,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LeaderboardDto {
+    pub campaign: String,
+    pub snapshots: Vec<LeaderboardSnapshotDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LeaderboardSnapshotDto {
+    pub date: String,
+    pub empires: Vec<LeaderboardEntryDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LeaderboardEntryDto {
+    pub name: String,
+    pub victory_rank: i64,
+    pub victory_score: f64,
+    pub military_power: f64,
+    pub economy_power: f64,
+    pub tech_power: f64,
+    pub empire_size: f64,
+    pub fleet_size: i64,
+    pub starbase_capacity: i64,
+}
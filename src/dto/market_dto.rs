@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::ResourceClass;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct MarketDto {
+    pub campaign: String,
+    pub dates: Vec<String>,
+    pub prices: HashMap<ResourceClass, Vec<Option<f64>>>,
+    pub empires: Vec<TradeVolumeSeriesDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TradeVolumeSeriesDto {
+    pub empire: String,
+    pub bought: Vec<f64>,
+    pub sold: Vec<f64>,
+    pub net: Vec<f64>,
+}
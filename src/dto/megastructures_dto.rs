@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct MegastructuresDto {
+    pub empire: String,
+    pub megastructures: Vec<MegastructureDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct MegastructureDto {
+    pub id: i64,
+    pub system: i64,
+    pub megastructure_type: String,
+    pub stages: Vec<MegastructureStageDto>,
+}
+
+/// `completed` is `None` when the stage was already reached in the first snapshot of the range.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct MegastructureStageDto {
+    pub stage: String,
+    pub completed: Option<String>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct MilitarySeriesDto {
+    pub empire: String,
+    pub dates: Vec<String>,
+    pub total: Vec<f64>,
+    pub fleets: Vec<FleetSeriesDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct FleetSeriesDto {
+    pub id: i64,
+    pub name: String,
+    pub military_power: Vec<Option<f64>>,
+}
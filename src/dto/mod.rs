@@ -1,9 +1,47 @@
 mod budget_component;
+mod budget_dto;
 mod campaign_dto;
+mod campaign_update_dto;
+mod colonies_dto;
+mod demographics_series_dto;
+mod diplomacy_dto;
 mod empire_dto;
+mod galaxy_dto;
+mod identity_dto;
+mod leader_events_dto;
+mod leaderboard_dto;
+mod market_dto;
+mod megastructures_dto;
+mod military_series_dto;
+mod planet_dto;
+mod relations_dto;
 mod resource_class;
+mod resource_series_dto;
+mod technologies_dto;
+mod trade_dto;
+mod wars_dto;
 
 pub use budget_component::BudgetComponent;
+pub use budget_dto::{BudgetDto, ResourceBudgetDto};
 pub use campaign_dto::CampaignDto;
+pub use campaign_update_dto::CampaignUpdateDto;
+pub use colonies_dto::{ColoniesDto, ColonySnapshotDto};
+pub use demographics_series_dto::DemographicsSeriesDto;
+pub use diplomacy_dto::{DiplomacyDto, DiplomacySnapshotDto, FederationDto, GalacticCommunityDto};
 pub use empire_dto::EmpireDto;
+pub use galaxy_dto::{GalaxyDto, HyperlaneDto, SystemDto};
+pub use identity_dto::{
+    IdentityChangeDto, IdentityChangeKind, IdentityDto, IdentitySnapshotDto, PolicyDto,
+};
+pub use leader_events_dto::{LeaderEventDto, LeaderEventKind, LeaderEventsDto};
+pub use leaderboard_dto::{LeaderboardDto, LeaderboardEntryDto, LeaderboardSnapshotDto};
+pub use market_dto::{MarketDto, TradeVolumeSeriesDto};
+pub use megastructures_dto::{MegastructureDto, MegastructureStageDto, MegastructuresDto};
+pub use military_series_dto::{FleetSeriesDto, MilitarySeriesDto};
+pub use planet_dto::PlanetDto;
+pub use relations_dto::{RelationDto, RelationMatrixDto, RelationSnapshotDto};
 pub use resource_class::ResourceClass;
+pub use resource_series_dto::ResourceSeriesDto;
+pub use technologies_dto::{TechnologiesDto, TechnologyDto};
+pub use trade_dto::{TradeDto, TradeHubDto, TradeRouteDto, TradeSnapshotDto};
+pub use wars_dto::{TruceDto, WarDto, WarsDto};
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct PlanetDto {
+    pub id: i64,
+    pub name: String,
+    pub class: String,
+    pub size: i64,
+    pub owner: i64,
+    pub controller: i64,
+    pub pops: i64,
+    pub districts: Vec<String>,
+    pub buildings: Vec<String>,
+    pub stability: f64,
+    pub amenities: f64,
+    pub housing: f64,
+    pub crime: f64,
+}
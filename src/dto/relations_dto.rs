@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct RelationMatrixDto {
+    pub campaign: String,
+    pub countries: Vec<String>,
+    pub snapshots: Vec<RelationSnapshotDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct RelationSnapshotDto {
+    pub date: String,
+    pub matrix: Vec<Vec<Option<RelationDto>>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct RelationDto {
+    pub opinion: i64,
+    pub trust: f64,
+    pub treaties: Vec<String>,
+    pub rival: bool,
+    pub borders: bool,
+}
@@ -1,6 +1,9 @@
-use strum_macros::EnumIter;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
 
-#[derive(Debug, EnumIter, PartialEq, Eq, Hash, Clone)]
+#[derive(
+    Deserialize, Serialize, Debug, Display, EnumIter, EnumString, PartialEq, Eq, Hash, Clone,
+)]
 pub enum ResourceClass {
     Energy,
     Minerals,
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::ResourceClass;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct ResourceSeriesDto {
+    pub empire: String,
+    pub dates: Vec<String>,
+    pub resources: HashMap<ResourceClass, Vec<f64>>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TechnologiesDto {
+    pub empire: String,
+    pub technologies: Vec<TechnologyDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TechnologyDto {
+    pub technology: String,
+    pub first_seen: String,
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TradeDto {
+    pub empire: String,
+    pub snapshots: Vec<TradeSnapshotDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TradeSnapshotDto {
+    pub date: String,
+    pub trade_value: f64,
+    pub collected: f64,
+    pub piracy_losses: f64,
+    pub hubs: Vec<TradeHubDto>,
+    pub routes: Vec<TradeRouteDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TradeHubDto {
+    pub system: i64,
+    pub collected: f64,
+    pub collected_from: Vec<i64>,
+    pub piracy: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TradeRouteDto {
+    pub id: i64,
+    pub from: i64,
+    pub to: i64,
+    pub delivered: f64,
+    pub path: Vec<i64>,
+}
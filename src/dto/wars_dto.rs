@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct WarsDto {
+    pub campaign: String,
+    pub wars: Vec<WarDto>,
+    pub truces: Vec<TruceDto>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct WarDto {
+    pub id: i64,
+    pub name: String,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub active: bool,
+    pub attackers: Vec<String>,
+    pub defenders: Vec<String>,
+    pub attacker_war_goal: String,
+    pub defender_war_goal: Option<String>,
+    pub dates: Vec<String>,
+    pub attacker_exhaustion: Vec<f64>,
+    pub defender_exhaustion: Vec<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TruceDto {
+    pub id: i64,
+    pub name: String,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub active: bool,
+    pub truce_type: Option<String>,
+    pub countries: Vec<String>,
+}
@@ -1,10 +1,10 @@
 use std::{
     fs,
     path::{Path, PathBuf},
-    process::Command,
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use sqlx::{migrate::MigrateDatabase, Sqlite};
 
 const CAMPAIGN_UNE_ROOT_RELATIVE: &str =
     "stellarust/res/test_data/campaign/unitednationsofearth_-15512622/";
@@ -24,51 +24,40 @@ pub fn get_path(path: &str) -> PathBuf {
     PathBuf::from_iter(vec![&cwd, &PathBuf::from(path)])
 }
 
-pub fn create_sqlite_db<PATH, NAME>(path_root: &PATH, name: &NAME) -> Result<()>
+pub async fn create_sqlite_db<PATH, NAME>(path_root: &PATH, name: &NAME) -> Result<()>
 where
     PATH: AsRef<Path>,
     NAME: AsRef<str>,
 {
-    _create_sqlite_db(path_root.as_ref(), name.as_ref())
+    _create_sqlite_db(path_root.as_ref(), name.as_ref()).await
 }
 
-fn _create_sqlite_db(path_root: &Path, name: &str) -> Result<()> {
-    let database_url = format!(
-        "sqlite:{}",
-        PathBuf::from_iter(vec![path_root.to_str().unwrap(), name])
-            .to_str()
-            .unwrap()
-    );
-
-    Command::new("sqlx")
-        .args(&["database", "create", "--database-url", &database_url])
-        .output()
-        .unwrap();
+async fn _create_sqlite_db(path_root: &Path, name: &str) -> Result<()> {
+    Sqlite::create_database(&get_database_url(path_root, name)?).await?;
     Ok(())
 }
 
-pub fn drop_sqlite_db<PATH, NAME>(path_root: &PATH, name: &NAME) -> Result<()>
+pub async fn drop_sqlite_db<PATH, NAME>(path_root: &PATH, name: &NAME) -> Result<()>
 where
     PATH: AsRef<Path>,
     NAME: AsRef<str>,
 {
-    _drop_sqlite_db(path_root.as_ref(), name.as_ref())
+    _drop_sqlite_db(path_root.as_ref(), name.as_ref()).await
 }
-fn _drop_sqlite_db(path_root: &Path, name: &str) -> Result<()> {
-    let database_url = format!(
-        "sqlite:{}",
-        PathBuf::from_iter(vec![path_root.to_str().unwrap(), name])
-            .to_str()
-            .unwrap()
-    );
 
-    Command::new("sqlx")
-        .args(&["database", "drop", "--database-url", &database_url, "-y"])
-        .output()
-        .unwrap();
+async fn _drop_sqlite_db(path_root: &Path, name: &str) -> Result<()> {
+    Sqlite::drop_database(&get_database_url(path_root, name)?).await?;
     Ok(())
 }
 
+fn get_database_url(path_root: &Path, name: &str) -> Result<String> {
+    let path = PathBuf::from_iter(vec![path_root, Path::new(name)]);
+    match path.to_str() {
+        Some(path) => Ok(format!("sqlite:{}", path)),
+        None => Err(anyhow!("{:?} is not valid unicode", path)),
+    }
+}
+
 pub fn cleanup_sqlite<PATH, NAME>(path_root: &PATH, name: &NAME)
 where
     PATH: AsRef<Path>,